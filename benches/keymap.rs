@@ -0,0 +1,89 @@
+//! Measures serde of a large keymap like the one of an application with many shortcuts
+//! and of the key modifiers alone.
+//!
+//! Run with `cargo bench --bench keymap`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hint::black_box;
+
+const BINDINGS: usize = 300;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Config {
+    #[serde(with = "crossterm_serde::serde_keymap")]
+    keymap: HashMap<String, KeyEvent>,
+}
+
+#[derive(Debug, Serialize)]
+struct Modifiers(#[serde(with = "crossterm_serde::serde_key_modifier")] KeyModifiers);
+
+fn large_config() -> Config {
+    let codes = [
+        KeyCode::Up,
+        KeyCode::PageDown,
+        KeyCode::F(5),
+        KeyCode::Char('a'),
+        KeyCode::Char('Z'),
+        KeyCode::Enter,
+    ];
+    let modifiers = [
+        KeyModifiers::NONE,
+        KeyModifiers::CONTROL,
+        KeyModifiers::ALT | KeyModifiers::SHIFT,
+        KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER,
+    ];
+    let keymap = (0..BINDINGS)
+        .map(|index| {
+            let event = KeyEvent::new(
+                codes[index % codes.len()],
+                modifiers[index % modifiers.len()],
+            );
+            (format!("action_{}", index), event)
+        })
+        .collect();
+    Config { keymap }
+}
+
+fn keymap(criterion: &mut Criterion) {
+    let config = large_config();
+    let json = serde_json::to_string(&config).unwrap();
+    let ron = ron::to_string(&config).unwrap();
+
+    let mut group = criterion.benchmark_group(format!("keymap of {} bindings", BINDINGS));
+    group.bench_function("json serialize", |bencher| {
+        bencher.iter(|| serde_json::to_string(black_box(&config)).unwrap())
+    });
+    group.bench_function("json deserialize", |bencher| {
+        bencher.iter(|| serde_json::from_str::<Config>(black_box(&json)).unwrap())
+    });
+    group.bench_function("ron serialize", |bencher| {
+        bencher.iter(|| ron::to_string(black_box(&config)).unwrap())
+    });
+    group.bench_function("ron deserialize", |bencher| {
+        bencher.iter(|| ron::from_str::<Config>(black_box(&ron)).unwrap())
+    });
+    group.finish();
+}
+
+/// Writes every combination of the known modifiers as joined keywords like "CONTROL+ALT".
+fn modifiers(criterion: &mut Criterion) {
+    let all: Vec<Modifiers> = (0..=u8::MAX)
+        .filter_map(KeyModifiers::from_bits)
+        .map(Modifiers)
+        .collect();
+    let mut buffer = Vec::with_capacity(64);
+    criterion.bench_function("modifiers serialize", |bencher| {
+        bencher.iter(|| {
+            for next in &all {
+                buffer.clear();
+                serde_json::to_writer(&mut buffer, black_box(next)).unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, keymap, modifiers);
+criterion_main!(benches);
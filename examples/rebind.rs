@@ -0,0 +1,66 @@
+//! Rebinds one action of a keymap stored as RON by pressing the new key.
+//!
+//! Run with `cargo run --example rebind -- keymap.ron move_up`.
+//! The file is created if it does not exist yet.
+
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use crossterm::terminal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::{env, fs, io};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Config {
+    #[serde(with = "crossterm_serde::serde_keymap")]
+    keymap: HashMap<String, KeyEvent>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+    let (Some(path), Some(action)) = (args.next(), args.next()) else {
+        eprintln!("Usage: rebind <keymap.ron> <action>");
+        std::process::exit(2);
+    };
+
+    let mut config: Config = match fs::read_to_string(&path) {
+        Ok(content) => ron::from_str(&content)?,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Config::default(),
+        Err(error) => return Err(error.into()),
+    };
+    if let Some(old) = config.keymap.get(&action) {
+        println!(
+            "{} is bound to {}",
+            action,
+            crossterm_serde::key_event_to_string(old)?
+        );
+    }
+
+    println!("Press the new key for {}", action);
+    let event = read_key()?;
+    let text = crossterm_serde::key_event_to_string(&event)?;
+    // The compact form is parsed back to show it is the same binding as the pressed key.
+    assert_eq!(
+        crossterm_serde::BindingKey::from(event),
+        crossterm_serde::key_event_from_str(&text)?.into()
+    );
+    println!("{} is now bound to {}", action, text);
+
+    config.keymap.insert(action, event);
+    let content = ron::ser::to_string_pretty(&config, ron::ser::PrettyConfig::default())?;
+    fs::write(&path, content)?;
+    Ok(())
+}
+
+/// Waits in raw mode for the press of a key, so keys like CTRL+c arrive as key events.
+fn read_key() -> io::Result<KeyEvent> {
+    terminal::enable_raw_mode()?;
+    let pressed = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => break Ok(key),
+            Ok(_) => continue,
+            Err(error) => break Err(error),
+        }
+    };
+    terminal::disable_raw_mode()?;
+    pressed
+}
@@ -0,0 +1,22 @@
+//! Uses the parsing and formatting of crossterm_serde without std.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use crossterm::event::KeyModifiers;
+use crossterm_serde::{
+    bits_to_strs, key_code_to_text, parse_key_code, parse_key_modifier, KeyParseError,
+};
+
+/// Key code of a text written back in its canonical form like "Esc" for "Escape".
+pub fn canonical_key_code(text: &str) -> Result<Cow<'static, str>, KeyParseError> {
+    key_code_to_text(&parse_key_code(text)?)
+}
+
+/// Keywords of the modifiers of a text in canonical order like ["CONTROL", "ALT"].
+pub fn canonical_modifiers(text: &str) -> Result<Vec<&'static str>, KeyParseError> {
+    parse_key_modifier(text).map(|modifiers: KeyModifiers| bits_to_strs(&modifiers))
+}
@@ -0,0 +1,321 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventState, KeyModifiers};
+use std::hash::{Hash, Hasher};
+
+use crate::key_text::key_code::CONTROL_CHARS;
+
+/// Key event reduced to its code and modifiers.
+///
+/// The kind and state of a key event are ignored. A binding from a configuration file has always
+/// the kind press while an incoming event might also be a repeat or come from the keypad.
+/// This makes it suitable as key for looking up the action of an incoming key event.
+///
+/// Like the equality of [`KeyEvent`], an uppercase ASCII char is the same key
+/// as the lowercase char with SHIFT. Both are stored as the uppercase char with SHIFT.
+///
+/// Terminals report SHIFT+Tab as BackTab with or without SHIFT, other paths as Tab with SHIFT.
+/// All of them are stored as BackTab without SHIFT.
+///
+/// Some terminals report a key with its own key code as its control char.
+/// These chars are stored as the key code of the key:
+///
+/// | Char                 | Key code  |
+/// |----------------------|-----------|
+/// | '\t'                 | Tab       |
+/// | '\n' and '\r'        | Enter     |
+/// | '\u{1b}'             | Esc       |
+/// | '\u{8}' and '\u{7f}' | Backspace |
+/// | '\u{0}'              | Null      |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BindingKey {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl BindingKey {
+    /// Normalizes the code and modifiers like described for [`BindingKey`].
+    /// The fields are private, so every binding key is normalized.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers = modifiers;
+        let code = match code {
+            KeyCode::Char(control) => CONTROL_CHARS
+                .iter()
+                .find(|(next, _)| *next == control)
+                .map_or(code, |(_, named)| *named),
+            code => code,
+        };
+        let code = match code {
+            KeyCode::Char(char) if char.is_ascii_uppercase() => {
+                modifiers.insert(KeyModifiers::SHIFT);
+                code
+            }
+            KeyCode::Char(char) if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(char.to_ascii_uppercase())
+            }
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                code
+            }
+            code => code,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Key code after the normalization like BackTab for SHIFT+Tab.
+    pub fn code(&self) -> KeyCode {
+        self.code
+    }
+
+    /// Modifiers after the normalization like SHIFT for an uppercase char.
+    pub fn modifiers(&self) -> KeyModifiers {
+        self.modifiers
+    }
+}
+
+impl From<KeyEvent> for BindingKey {
+    fn from(event: KeyEvent) -> Self {
+        Self::new(event.code, event.modifiers)
+    }
+}
+
+impl From<&KeyEvent> for BindingKey {
+    fn from(event: &KeyEvent) -> Self {
+        Self::from(*event)
+    }
+}
+
+impl From<BindingKey> for KeyEvent {
+    fn from(key: BindingKey) -> Self {
+        KeyEvent::new(key.code, key.modifiers)
+    }
+}
+
+/// Key event which is equal and hashed like its [`BindingKey`], so kind and state are ignored.
+///
+/// Unlike [`BindingKey`] the whole key event is kept, like for a `HashMap<MatchKey, Action>`
+/// whose keys are written back into a config.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+/// use crossterm_serde::MatchKey;
+/// use std::collections::HashMap;
+///
+/// let bindings = HashMap::from([(MatchKey(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)), "up")]);
+/// let incoming = KeyEvent::new_with_kind(KeyCode::Up, KeyModifiers::NONE, KeyEventKind::Repeat);
+/// assert_eq!(Some(&"up"), bindings.get(&MatchKey(incoming)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MatchKey(pub KeyEvent);
+
+impl PartialEq for MatchKey {
+    fn eq(&self, other: &Self) -> bool {
+        BindingKey::from(&self.0) == BindingKey::from(&other.0)
+    }
+}
+
+impl Eq for MatchKey {}
+
+impl Hash for MatchKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        BindingKey::from(&self.0).hash(state);
+    }
+}
+
+impl From<KeyEvent> for MatchKey {
+    fn from(event: KeyEvent) -> Self {
+        Self(event)
+    }
+}
+
+/// Returns true if the configured key event has the same code and modifiers as the incoming one.
+///
+/// Kind and state are ignored. A deserialized key event has always the kind press and no state,
+/// while the incoming event of a terminal might be a repeat or come from the keypad.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+/// use crossterm_serde::key_event_matches;
+///
+/// let config = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+/// let incoming =
+///     KeyEvent::new_with_kind(KeyCode::Char('s'), KeyModifiers::CONTROL, KeyEventKind::Repeat);
+/// assert!(key_event_matches(&config, &incoming));
+/// ```
+pub fn key_event_matches(config: &KeyEvent, incoming: &KeyEvent) -> bool {
+    BindingKey::from(config) == BindingKey::from(incoming)
+}
+
+/// Returns true like [`key_event_matches`] and if both key events come from the keypad or not.
+///
+/// This allows a binding for the Enter key of the keypad which does not fire for the main Enter.
+/// Other states and the kind are still ignored.
+pub fn key_event_matches_keypad(config: &KeyEvent, incoming: &KeyEvent) -> bool {
+    key_event_matches(config, incoming)
+        && config.state.contains(KeyEventState::KEYPAD)
+            == incoming.state.contains(KeyEventState::KEYPAD)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::KeyEventKind;
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_ignore_kind_and_state() {
+        let press = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::ALT);
+        let repeat = KeyEvent::new_with_kind_and_state(
+            KeyCode::Char('a'),
+            KeyModifiers::ALT,
+            KeyEventKind::Repeat,
+            KeyEventState::KEYPAD,
+        );
+        assert_eq!(BindingKey::from(press), BindingKey::from(repeat));
+    }
+    #[test]
+    fn should_look_up_repeat_of_press_binding() {
+        let bindings = HashMap::from([(
+            BindingKey::from(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+            "down",
+        )]);
+        for kind in [
+            KeyEventKind::Press,
+            KeyEventKind::Repeat,
+            KeyEventKind::Release,
+        ] {
+            let incoming = KeyEvent::new_with_kind(KeyCode::Char('j'), KeyModifiers::NONE, kind);
+            assert_eq!(Some(&"down"), bindings.get(&BindingKey::from(incoming)));
+        }
+        let other = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::ALT);
+        assert_eq!(None, bindings.get(&BindingKey::from(other)));
+    }
+    #[test]
+    fn should_hash_and_compare_press_and_repeat_of_match_key_equal() {
+        use std::collections::hash_map::DefaultHasher;
+        fn hash(key: &MatchKey) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            hasher.finish()
+        }
+        let press = MatchKey(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        let repeat = MatchKey(KeyEvent::new_with_kind_and_state(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            KeyEventKind::Repeat,
+            KeyEventState::KEYPAD,
+        ));
+        assert_eq!(press, repeat);
+        assert_eq!(hash(&press), hash(&repeat));
+        let other = MatchKey(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::ALT));
+        assert_ne!(press, other);
+
+        let bindings = HashMap::from([(press, "down")]);
+        assert_eq!(Some(&"down"), bindings.get(&repeat));
+        assert_eq!(KeyEventKind::Press, bindings.keys().next().unwrap().0.kind);
+    }
+    #[test]
+    fn should_match_with_different_kind_and_state() {
+        let config = KeyEvent::new(KeyCode::Up, KeyModifiers::ALT);
+        assert_case(KeyEventKind::Press, KeyEventState::NONE);
+        assert_case(KeyEventKind::Repeat, KeyEventState::NONE);
+        assert_case(KeyEventKind::Release, KeyEventState::KEYPAD);
+        assert_case(KeyEventKind::Press, KeyEventState::CAPS_LOCK);
+        fn assert_case(kind: KeyEventKind, state: KeyEventState) {
+            let config = KeyEvent::new(KeyCode::Up, KeyModifiers::ALT);
+            let incoming =
+                KeyEvent::new_with_kind_and_state(KeyCode::Up, KeyModifiers::ALT, kind, state);
+            assert!(key_event_matches(&config, &incoming));
+        }
+        let other_code = KeyEvent::new(KeyCode::Down, KeyModifiers::ALT);
+        assert!(!key_event_matches(&config, &other_code));
+        let other_modifiers = KeyEvent::new(KeyCode::Up, KeyModifiers::CONTROL);
+        assert!(!key_event_matches(&config, &other_modifiers));
+    }
+    #[test]
+    fn should_match_keypad_only_with_keypad() {
+        let keypad = KeyEvent::new_with_kind_and_state(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            KeyEventKind::Press,
+            KeyEventState::KEYPAD,
+        );
+        let main = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        let keypad_repeat = KeyEvent::new_with_kind_and_state(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            KeyEventKind::Repeat,
+            KeyEventState::KEYPAD | KeyEventState::NUM_LOCK,
+        );
+        assert!(key_event_matches_keypad(&keypad, &keypad_repeat));
+        assert!(!key_event_matches_keypad(&keypad, &main));
+        assert!(!key_event_matches_keypad(&main, &keypad));
+        assert!(key_event_matches(&keypad, &main));
+    }
+    #[test]
+    fn should_treat_shift_tab_as_back_tab() {
+        let config = KeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE);
+        let with_shift = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+        let tab_with_shift = KeyEvent::new(KeyCode::Tab, KeyModifiers::SHIFT);
+        assert!(key_event_matches(&config, &with_shift));
+        assert!(key_event_matches(&config, &tab_with_shift));
+        assert!(key_event_matches(&tab_with_shift, &config));
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        assert!(!key_event_matches(&config, &tab));
+    }
+    #[test]
+    fn should_treat_control_chars_as_their_key() {
+        assert_case('\t', KeyCode::Tab);
+        assert_case('\n', KeyCode::Enter);
+        assert_case('\r', KeyCode::Enter);
+        assert_case('\u{1b}', KeyCode::Esc);
+        assert_case('\u{8}', KeyCode::Backspace);
+        assert_case('\u{7f}', KeyCode::Backspace);
+        assert_case('\u{0}', KeyCode::Null);
+        fn assert_case(control: char, code: KeyCode) {
+            let named = KeyEvent::new(code, KeyModifiers::NONE);
+            let char = KeyEvent::new(KeyCode::Char(control), KeyModifiers::NONE);
+            assert!(key_event_matches(&named, &char), "{:?}", control);
+            assert!(key_event_matches(&char, &named), "{:?}", control);
+        }
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        let config = crate::key_event_from_str("Space").unwrap();
+        assert!(key_event_matches(&config, &space));
+        let config = crate::key_event_from_str("BackTab").unwrap();
+        let shift_tab = KeyEvent::new(KeyCode::Char('\t'), KeyModifiers::SHIFT);
+        assert!(key_event_matches(&config, &shift_tab));
+        let tab = KeyEvent::new(KeyCode::Char('\t'), KeyModifiers::NONE);
+        assert!(!key_event_matches(&config, &tab));
+    }
+    #[test]
+    fn should_treat_uppercase_as_shift() {
+        let expected = BindingKey::new(KeyCode::Char('A'), KeyModifiers::NONE);
+        assert_eq!(
+            expected,
+            BindingKey::new(KeyCode::Char('a'), KeyModifiers::SHIFT)
+        );
+        assert_eq!(
+            expected,
+            BindingKey::new(KeyCode::Char('A'), KeyModifiers::SHIFT)
+        );
+        assert_ne!(
+            expected,
+            BindingKey::new(KeyCode::Char('a'), KeyModifiers::NONE)
+        );
+    }
+    #[test]
+    fn should_give_normalized_code_and_modifiers() {
+        let key = BindingKey::new(KeyCode::Char('a'), KeyModifiers::SHIFT);
+        assert_eq!(KeyCode::Char('A'), key.code());
+        assert_eq!(KeyModifiers::SHIFT, key.modifiers());
+        let key = BindingKey::new(KeyCode::Tab, KeyModifiers::SHIFT);
+        assert_eq!(KeyCode::BackTab, key.code());
+        assert_eq!(KeyModifiers::NONE, key.modifiers());
+    }
+}
@@ -0,0 +1,298 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, ModifierKeyCode};
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::{self, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::binding_key::{key_event_matches, BindingKey};
+use crate::compact;
+use crate::key_event_serde::serde_key_code;
+use crate::key_text::{key_code, key_modifier};
+use crate::{ConfigModifiers, KeyParseError};
+
+/// Keyword in place of the modifiers for a binding which matches any modifiers like "ANY+Esc".
+const ANY: &str = "ANY";
+
+/// Key binding which matches exactly one key event, a key code with any modifiers
+/// or modifiers pressed alone.
+///
+/// The text is the compact form of a key event like "CONTROL+s".
+/// The keyword "ANY" instead of the modifiers like "ANY+Esc" matches the key code
+/// no matter which modifiers are pressed. It can not be combined with other modifiers.
+///
+/// SHIFT is one of the modifiers which may be pressed, so "ANY+a" also matches "A"
+/// and "ANY+Tab" also matches BackTab.
+///
+/// A trigger on modifiers alone like a tap on Shift has no key code. It is written as an object
+/// without the code field like `{ "modifiers": "SHIFT" }`. The other patterns can also be written
+/// as an object like `{ "code": "s", "modifiers": "CONTROL" }`.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::BindingPattern;
+///
+/// let cancel: BindingPattern = "ANY+Esc".parse().unwrap();
+/// assert_eq!(BindingPattern::AnyModifiers(KeyCode::Esc), cancel);
+/// assert!(cancel.matches(&KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+/// assert!(cancel.matches(&KeyEvent::new(KeyCode::Esc, KeyModifiers::CONTROL)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingPattern {
+    /// Matches the same key event like [`key_event_matches`](crate::key_event_matches).
+    Exact(KeyEvent),
+    /// Matches the key code with any modifiers.
+    AnyModifiers(KeyCode),
+    /// Matches a modifier key like LeftShift which is pressed alone,
+    /// so the modifiers together with the pressed modifier key are these modifiers.
+    ModifiersOnly(KeyModifiers),
+}
+
+impl BindingPattern {
+    /// Returns true if the incoming key event fits this pattern. Kind and state are ignored.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        match self {
+            BindingPattern::Exact(config) => key_event_matches(config, event),
+            BindingPattern::AnyModifiers(code) => {
+                BindingKey::new(*code, KeyModifiers::SHIFT).code()
+                    == BindingKey::new(event.code, KeyModifiers::SHIFT).code()
+            }
+            BindingPattern::ModifiersOnly(modifiers) => match event.code {
+                KeyCode::Modifier(key) => event.modifiers | modifier_of_key(key) == *modifiers,
+                _ => false,
+            },
+        }
+    }
+
+    fn to_text(self) -> Result<String, KeyParseError> {
+        match self {
+            BindingPattern::Exact(event) => compact::key_event_to_string(&event),
+            BindingPattern::AnyModifiers(code) => {
+                let code = key_code::key_code_to_text(&code)?;
+                Ok(format!("{}{}{}", ANY, key_modifier::SEPERATOR, code))
+            }
+            BindingPattern::ModifiersOnly(modifiers) => Err(KeyParseError::MissingKeyCode(
+                ConfigModifiers(modifiers).to_string(),
+            )),
+        }
+    }
+}
+
+/// Modifier which is pressed by a modifier key. The ISO level shifts have no modifier.
+fn modifier_of_key(key: ModifierKeyCode) -> KeyModifiers {
+    match key {
+        ModifierKeyCode::LeftShift | ModifierKeyCode::RightShift => KeyModifiers::SHIFT,
+        ModifierKeyCode::LeftControl | ModifierKeyCode::RightControl => KeyModifiers::CONTROL,
+        ModifierKeyCode::LeftAlt | ModifierKeyCode::RightAlt => KeyModifiers::ALT,
+        ModifierKeyCode::LeftSuper | ModifierKeyCode::RightSuper => KeyModifiers::SUPER,
+        ModifierKeyCode::LeftHyper | ModifierKeyCode::RightHyper => KeyModifiers::HYPER,
+        ModifierKeyCode::LeftMeta | ModifierKeyCode::RightMeta => KeyModifiers::META,
+        ModifierKeyCode::IsoLevel3Shift | ModifierKeyCode::IsoLevel5Shift => KeyModifiers::NONE,
+    }
+}
+
+impl From<KeyEvent> for BindingPattern {
+    fn from(event: KeyEvent) -> Self {
+        Self::Exact(event)
+    }
+}
+
+impl FromStr for BindingPattern {
+    type Err = KeyParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (modifiers, code) = compact::split_modifiers(text);
+        if modifiers.trim().eq_ignore_ascii_case(ANY) {
+            let code = key_code::parse_key_code(code.trim())?;
+            Ok(Self::AnyModifiers(code))
+        } else {
+            compact::key_event_from_str(text).map(Self::Exact)
+        }
+    }
+}
+
+impl Serialize for BindingPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let BindingPattern::ModifiersOnly(modifiers) = self {
+            let mut state = serializer.serialize_struct("BindingPattern", 1)?;
+            state.serialize_field("modifiers", &ConfigModifiers(*modifiers))?;
+            return state.end();
+        }
+        let text = self.to_text().map_err(ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for BindingPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(BindingPatternVisitor)
+    }
+}
+
+/// Fields of a binding pattern given as object instead of text.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PatternFields {
+    #[serde(default, deserialize_with = "deserialize_code")]
+    code: Option<KeyCode>,
+    modifiers: Option<ConfigModifiers>,
+}
+
+fn deserialize_code<'de, D>(deserializer: D) -> Result<Option<KeyCode>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    serde_key_code::deserialize(deserializer).map(Some)
+}
+
+struct BindingPatternVisitor;
+
+impl<'de> Visitor<'de> for BindingPatternVisitor {
+    type Value = BindingPattern;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a key event like CONTROL+s or an object with modifiers and no code")
+    }
+
+    fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        text.parse().map_err(de::Error::custom)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let fields = PatternFields::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        match (fields.code, fields.modifiers) {
+            (Some(code), modifiers) => Ok(BindingPattern::Exact(KeyEvent::new(
+                code,
+                modifiers.unwrap_or_default().0,
+            ))),
+            (None, Some(ConfigModifiers(modifiers))) if !modifiers.is_empty() => {
+                Ok(BindingPattern::ModifiersOnly(modifiers))
+            }
+            (None, _) => Err(de::Error::custom(
+                "a pattern without code needs at least one modifier",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_parse_any_modifiers() {
+        assert_case("ANY+Esc", BindingPattern::AnyModifiers(KeyCode::Esc));
+        assert_case("any+a", BindingPattern::AnyModifiers(KeyCode::Char('a')));
+        assert_case("ANY++", BindingPattern::AnyModifiers(KeyCode::Char('+')));
+        assert_case(
+            "CONTROL+s",
+            BindingPattern::Exact(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+        );
+        fn assert_case(input: &str, expected: BindingPattern) {
+            let actual: BindingPattern = input.parse().unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+    #[test]
+    fn should_deny_any_with_other_modifiers() {
+        assert_eq!(
+            Err(KeyParseError::UnknownModifier("ANY".to_string())),
+            "ANY+CONTROL+a".parse::<BindingPattern>()
+        );
+    }
+    #[test]
+    fn should_match_any_modifiers() {
+        let pattern: BindingPattern = "ANY+Esc".parse().unwrap();
+        assert_case(&pattern, KeyCode::Esc, KeyModifiers::NONE, true);
+        assert_case(&pattern, KeyCode::Esc, KeyModifiers::CONTROL, true);
+        assert_case(
+            &pattern,
+            KeyCode::Esc,
+            KeyModifiers::SHIFT | KeyModifiers::ALT,
+            true,
+        );
+        assert_case(&pattern, KeyCode::Enter, KeyModifiers::NONE, false);
+        let pattern: BindingPattern = "ANY+a".parse().unwrap();
+        assert_case(&pattern, KeyCode::Char('A'), KeyModifiers::NONE, true);
+        assert_case(&pattern, KeyCode::Char('a'), KeyModifiers::ALT, true);
+    }
+    #[test]
+    fn should_match_exact_modifiers() {
+        let pattern: BindingPattern = "CONTROL+s".parse().unwrap();
+        assert_case(&pattern, KeyCode::Char('s'), KeyModifiers::CONTROL, true);
+        assert_case(&pattern, KeyCode::Char('s'), KeyModifiers::NONE, false);
+        assert_case(
+            &pattern,
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+            false,
+        );
+    }
+    #[test]
+    fn should_round_trip_via_serde() {
+        assert_case(r#""ANY+Esc""#);
+        assert_case(r#""CONTROL+s""#);
+        fn assert_case(input: &str) {
+            let pattern: BindingPattern = serde_json::from_str(input).unwrap();
+            let actual = serde_json::to_string(&pattern).unwrap();
+            assert_eq!(input, actual);
+        }
+    }
+
+    #[test]
+    fn should_deserialize_modifiers_only() {
+        let actual: BindingPattern = serde_json::from_str(r#"{ "modifiers": "SHIFT" }"#).unwrap();
+        assert_eq!(BindingPattern::ModifiersOnly(KeyModifiers::SHIFT), actual);
+        assert_eq!(
+            r#"{"modifiers":"SHIFT"}"#,
+            serde_json::to_string(&actual).unwrap()
+        );
+        let actual: BindingPattern =
+            serde_json::from_str(r#"{ "code": "s", "modifiers": "CONTROL" }"#).unwrap();
+        assert_eq!(
+            BindingPattern::Exact(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            actual
+        );
+        assert!(serde_json::from_str::<BindingPattern>("{}").is_err());
+        assert!(serde_json::from_str::<BindingPattern>(r#"{ "modifiers": "NONE" }"#).is_err());
+    }
+    #[test]
+    fn should_match_modifiers_only() {
+        let pattern = BindingPattern::ModifiersOnly(KeyModifiers::SHIFT);
+        let shift = KeyCode::Modifier(ModifierKeyCode::LeftShift);
+        assert_case(&pattern, shift, KeyModifiers::NONE, true);
+        assert_case(&pattern, shift, KeyModifiers::SHIFT, true);
+        assert_case(
+            &pattern,
+            KeyCode::Modifier(ModifierKeyCode::RightShift),
+            KeyModifiers::NONE,
+            true,
+        );
+        assert_case(&pattern, shift, KeyModifiers::CONTROL, false);
+        assert_case(&pattern, KeyCode::Char('A'), KeyModifiers::SHIFT, false);
+    }
+
+    fn assert_case(
+        pattern: &BindingPattern,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        expected: bool,
+    ) {
+        let event = KeyEvent::new(code, modifiers);
+        assert_eq!(expected, pattern.matches(&event), "Event: {:?}", event);
+    }
+}
@@ -0,0 +1,1610 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::compact;
+use crate::key_event_serde::{serde_key_code, serde_key_event_kind, serde_key_modifier};
+use crate::key_text::{key_code, key_modifier, trim_config_text};
+use crate::{KeyParseError, ParseMode};
+
+/// How the modifiers of a key event are written on serialize.
+///
+/// On deserialize every style is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModifiersStyle {
+    /// Keywords joined by "+" like "CONTROL+ALT".
+    #[default]
+    Joined,
+    /// Sequence of keywords like ["CONTROL", "ALT"].
+    Array,
+    /// Object with a flag for every pressed modifier like `{ "CONTROL": true, "ALT": true }`.
+    /// No modifiers are an empty object.
+    Flags,
+}
+
+/// Case of the modifier keywords on serialize.
+///
+/// On deserialize the case does not matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModifierCase {
+    /// Keywords like "CONTROL+ALT".
+    #[default]
+    Uppercase,
+    /// Keywords like "control+alt".
+    Lowercase,
+}
+
+/// Whether a key event without modifiers has the modifiers field on serialize.
+///
+/// On deserialize a missing modifiers field is always accepted as NONE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoneModifiers {
+    /// The modifiers field is written like `{ "code": "Up", "modifiers": "NONE" }`.
+    #[default]
+    Explicit,
+    /// The modifiers field is left out like `{ "code": "Up" }`.
+    Omit,
+}
+
+/// How an empty text for the modifiers like "" or an empty sequence like [] is read on deserialize.
+///
+/// A missing modifiers field and an object of flags without a true flag are always read as NONE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlankModifiers {
+    /// An empty text or sequence is an error to catch accidental blanks.
+    #[default]
+    Reject,
+    /// An empty text or sequence is read as NONE like a missing modifiers field.
+    AsNone,
+}
+
+/// How the modifiers field of a key event without modifiers is written on serialize.
+///
+/// It only matters if the field is written, see [`NoneModifiers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyModifiers {
+    /// The keyword NONE like `"NONE"` or `["NONE"]`.
+    #[default]
+    AsNone,
+    /// An empty text like `""` or an empty sequence like `[]`.
+    /// On deserialize an empty text is then read as NONE whatever the [`BlankModifiers`] are,
+    /// so the output can be read back. NONE is still accepted too.
+    AsEmpty,
+}
+
+/// Whether the kind of a key event is a field like `{ "code": "a", "kind": "Release" }`.
+///
+/// A key event without the kind field or in the compact form has the default kind
+/// of the codec, see [`KeyEventCodecBuilder::default_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KindField {
+    /// The kind is not written and a kind field is an unknown field on deserialize,
+    /// see [`UnknownFields`]. Every key event has the default kind.
+    #[default]
+    Omit,
+    /// The kind is always written and read if it is given.
+    Include,
+}
+
+/// Whether the KEYPAD state of a key event is a field like `{ "code": "Enter", "keypad": true }`.
+///
+/// Other bits of the state like CAPS_LOCK are never written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeypadField {
+    /// The state is not written and a keypad field is an unknown field on deserialize,
+    /// see [`UnknownFields`]. Every key event has no state.
+    #[default]
+    Omit,
+    /// The keypad field is written for a key event with the KEYPAD state and read if it is given.
+    Include,
+}
+
+/// What to do with fields besides code and modifiers on deserialize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownFields {
+    /// Unknown fields are skipped. This keeps configs of newer versions readable.
+    #[default]
+    Ignore,
+    /// Unknown fields like a misspelled "modifers" are an error.
+    Deny,
+}
+
+/// Words used for the modifier keywords on serialize.
+///
+/// On deserialize every naming is accepted. The [`ModifierCase`] is applied after the naming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModifierNaming {
+    /// Keywords like "SHIFT+CONTROL+SUPER".
+    #[default]
+    Screaming,
+    /// Keywords like "Shift+Control+Super".
+    TitleCase,
+    /// Keywords like on macOS "Shift+Control+Command" with Option for ALT and Command for SUPER.
+    MacStyle,
+    /// Keywords like on Windows "Shift+Control+Win" with Win for SUPER.
+    WindowsStyle,
+    /// [`MacStyle`](Self::MacStyle) on macOS, [`WindowsStyle`](Self::WindowsStyle) on Windows
+    /// and [`TitleCase`](Self::TitleCase) on any other platform it is compiled for.
+    Platform,
+}
+
+impl ModifierNaming {
+    fn name(self, keyword: &'static str) -> Cow<'static, str> {
+        match (self.resolve(), keyword) {
+            (ModifierNaming::Screaming, keyword) => Cow::Borrowed(keyword),
+            (ModifierNaming::MacStyle, "ALT") => Cow::Borrowed("Option"),
+            (ModifierNaming::MacStyle, "SUPER") => Cow::Borrowed("Command"),
+            (ModifierNaming::WindowsStyle, "SUPER") => Cow::Borrowed("Win"),
+            (_, keyword) => {
+                let mut chars = keyword.chars();
+                let first = chars.next().into_iter();
+                let rest = chars.flat_map(char::to_lowercase);
+                Cow::Owned(first.chain(rest).collect())
+            }
+        }
+    }
+
+    /// Naming for the target platform in place of [`Platform`](Self::Platform).
+    fn resolve(self) -> Self {
+        match self {
+            ModifierNaming::Platform if cfg!(target_os = "macos") => ModifierNaming::MacStyle,
+            ModifierNaming::Platform if cfg!(target_os = "windows") => ModifierNaming::WindowsStyle,
+            ModifierNaming::Platform => ModifierNaming::TitleCase,
+            naming => naming,
+        }
+    }
+}
+
+/// Order of the modifier keywords on serialize.
+///
+/// On deserialize the order does not matter. The order is applied to the keywords like
+/// "CONTROL" before the [`ModifierNaming`] and [`ModifierCase`].
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ModifierSort {
+    /// Order of [`CANONICAL_MODIFIER_ORDER`](crate::CANONICAL_MODIFIER_ORDER) like "SHIFT+CONTROL+ALT".
+    #[default]
+    Canonical,
+    /// Alphabetical order of the keywords like "ALT+CONTROL+SHIFT".
+    Alphabetical,
+    /// Order by a comparison of two keywords like "CONTROL" and "ALT".
+    Custom(fn(&str, &str) -> Ordering),
+}
+
+/// Two custom orders are equal if they are the same function.
+impl PartialEq for ModifierSort {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ModifierSort::Canonical, ModifierSort::Canonical)
+            | (ModifierSort::Alphabetical, ModifierSort::Alphabetical) => true,
+            (ModifierSort::Custom(left), ModifierSort::Custom(right)) => {
+                *left as usize == *right as usize
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ModifierSort {}
+
+impl ModifierSort {
+    fn sort(self, keywords: &mut [&'static str]) {
+        match self {
+            ModifierSort::Canonical => (),
+            ModifierSort::Alphabetical => keywords.sort_unstable(),
+            ModifierSort::Custom(compare) => keywords.sort_by(|left, right| compare(left, right)),
+        }
+    }
+}
+
+/// Key names which an app defines on top of the built-in keywords like pseudo keys of plugins.
+///
+/// Both functions map to key codes which crossterm has, like a private use char
+/// or a high function key which no terminal sends.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomKeyCodes {
+    /// Key code of a name like "Leader". It is only asked for a name which would be
+    /// an unknown keyword, so it can not shadow a built-in keyword or a char.
+    pub parse: fn(&str) -> Option<KeyCode>,
+    /// Name of a key code, which is written instead of its built-in text.
+    /// None writes the built-in text.
+    pub write: fn(&KeyCode) -> Option<String>,
+}
+
+/// Two custom key codes are equal if they have the same functions.
+impl PartialEq for CustomKeyCodes {
+    fn eq(&self, other: &Self) -> bool {
+        self.parse as usize == other.parse as usize && self.write as usize == other.write as usize
+    }
+}
+
+impl Eq for CustomKeyCodes {}
+
+/// How a letter pressed together with SHIFT is written on serialize.
+///
+/// Terminals report a capital A either as the char 'A' without modifiers or
+/// as the char 'a' with SHIFT. Without normalization both are written differently.
+/// The recommended canonical form is [`ShiftNormalization::Uppercase`].
+///
+/// Only chars with an upper and lower case are normalized. SHIFT stays for other chars like '1'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShiftNormalization {
+    /// Key code and modifiers are written as they are.
+    #[default]
+    Off,
+    /// A letter with SHIFT is written as the uppercase letter without SHIFT like "A".
+    Uppercase,
+    /// An uppercase letter is written as the lowercase letter with SHIFT like "SHIFT" and "a".
+    LowercaseWithShift,
+}
+
+impl ShiftNormalization {
+    fn normalize(self, event: &KeyEvent) -> KeyEvent {
+        let mut normalized = *event;
+        let KeyCode::Char(char) = event.code else {
+            return normalized;
+        };
+        match self {
+            ShiftNormalization::Off => (),
+            ShiftNormalization::Uppercase => {
+                if let Some(upper) = single_char(char.to_uppercase()) {
+                    if (event.modifiers.contains(KeyModifiers::SHIFT) && char.is_lowercase())
+                        || char.is_uppercase()
+                    {
+                        normalized.code = KeyCode::Char(upper);
+                        normalized.modifiers.remove(KeyModifiers::SHIFT);
+                    }
+                }
+            }
+            ShiftNormalization::LowercaseWithShift => {
+                if char.is_uppercase() {
+                    if let Some(lower) = single_char(char.to_lowercase()) {
+                        normalized.code = KeyCode::Char(lower);
+                        normalized.modifiers.insert(KeyModifiers::SHIFT);
+                    }
+                }
+            }
+        }
+        normalized
+    }
+}
+
+/// How SHIFT+Tab is written on serialize.
+///
+/// Terminals report SHIFT+Tab as BackTab with or without SHIFT, other paths as Tab with SHIFT.
+/// Without normalization these are written differently but mean the same key press.
+/// The recommended canonical form is [`BackTabNormalization::BackTab`],
+/// which is also used by [`key_event_matches`](crate::key_event_matches).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackTabNormalization {
+    /// Key code and modifiers are written as they are.
+    #[default]
+    Off,
+    /// Tab with SHIFT and BackTab with SHIFT are written as "BackTab" without SHIFT.
+    BackTab,
+    /// BackTab is written as "Tab" with SHIFT.
+    TabWithShift,
+}
+
+impl BackTabNormalization {
+    fn normalize(self, event: &KeyEvent) -> KeyEvent {
+        let mut normalized = *event;
+        match (self, event.code) {
+            (BackTabNormalization::BackTab, KeyCode::Tab)
+                if event.modifiers.contains(KeyModifiers::SHIFT) =>
+            {
+                normalized.code = KeyCode::BackTab;
+                normalized.modifiers.remove(KeyModifiers::SHIFT);
+            }
+            (BackTabNormalization::BackTab, KeyCode::BackTab) => {
+                normalized.modifiers.remove(KeyModifiers::SHIFT);
+            }
+            (BackTabNormalization::TabWithShift, KeyCode::BackTab) => {
+                normalized.code = KeyCode::Tab;
+                normalized.modifiers.insert(KeyModifiers::SHIFT);
+            }
+            _ => (),
+        }
+        normalized
+    }
+}
+
+/// Some chars like 'ß' become several chars in another case. These are not normalized.
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(single), None) => Some(single),
+        _ => None,
+    }
+}
+
+/// Configurable version of [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent).
+///
+/// The default codec produces the same output as `SerDeConfigKeyEvent`.
+/// Since serde does not allow to pass options to `#[serde(with = "...")]`,
+/// the codec is used via `serialize_with` and `deserialize_with` with small functions.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::{KeyEventCodec, ModifiersStyle};
+/// use serde::{Deserialize, Deserializer, Serialize, Serializer};
+///
+/// static CODEC: KeyEventCodec = KeyEventCodec::builder()
+///     .modifiers_style(ModifiersStyle::Array)
+///     .build();
+///
+/// fn serialize_key<S: Serializer>(event: &KeyEvent, serializer: S) -> Result<S::Ok, S::Error> {
+///     CODEC.serialize(event, serializer)
+/// }
+///
+/// fn deserialize_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<KeyEvent, D::Error> {
+///     CODEC.deserialize(deserializer)
+/// }
+///
+/// #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+/// pub struct KeyBoard {
+///     #[serde(serialize_with = "serialize_key", deserialize_with = "deserialize_key")]
+///     move_up: KeyEvent,
+/// }
+///
+/// let key_board = KeyBoard {
+///     move_up: KeyEvent::new(KeyCode::Up, KeyModifiers::ALT | KeyModifiers::CONTROL),
+/// };
+/// let string = serde_json::to_string(&key_board).unwrap();
+/// assert_eq!(r#"{"move_up":{"code":"Up","modifiers":["CONTROL","ALT"]}}"#, string);
+/// assert_eq!(key_board, serde_json::from_str(&string).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEventCodec {
+    modifiers_style: ModifiersStyle,
+    shift_normalization: ShiftNormalization,
+    back_tab_normalization: BackTabNormalization,
+    modifier_case: ModifierCase,
+    modifier_naming: ModifierNaming,
+    modifier_sort: ModifierSort,
+    parse_mode: ParseMode,
+    none_modifiers: NoneModifiers,
+    unknown_fields: UnknownFields,
+    blank_modifiers: BlankModifiers,
+    empty_modifiers: EmptyModifiers,
+    kind_field: KindField,
+    keypad_field: KeypadField,
+    default_kind: KeyEventKind,
+    allowed_kinds: &'static [KeyEventKind],
+    custom_key_codes: Option<CustomKeyCodes>,
+}
+
+impl Default for KeyEventCodec {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl KeyEventCodec {
+    pub const fn builder() -> KeyEventCodecBuilder {
+        KeyEventCodecBuilder {
+            codec: KeyEventCodec {
+                modifiers_style: ModifiersStyle::Joined,
+                shift_normalization: ShiftNormalization::Off,
+                back_tab_normalization: BackTabNormalization::Off,
+                modifier_case: ModifierCase::Uppercase,
+                modifier_naming: ModifierNaming::Screaming,
+                modifier_sort: ModifierSort::Canonical,
+                parse_mode: ParseMode::Lenient,
+                none_modifiers: NoneModifiers::Explicit,
+                unknown_fields: UnknownFields::Ignore,
+                blank_modifiers: BlankModifiers::Reject,
+                empty_modifiers: EmptyModifiers::AsNone,
+                kind_field: KindField::Omit,
+                keypad_field: KeypadField::Omit,
+                default_kind: KeyEventKind::Press,
+                allowed_kinds: &[
+                    KeyEventKind::Press,
+                    KeyEventKind::Repeat,
+                    KeyEventKind::Release,
+                ],
+                custom_key_codes: None,
+            },
+        }
+    }
+
+    pub fn serialize<S>(&self, event: &KeyEvent, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let event = &self.normalize(event);
+        // A format which is not self-describing like bincode reads the fields by position,
+        // so every field of the options is written.
+        let readable = serializer.is_human_readable();
+        let omit_modifiers =
+            readable && self.none_modifiers == NoneModifiers::Omit && event.modifiers.is_empty();
+        let is_keypad = event.state.contains(KeyEventState::KEYPAD);
+        let keypad = self.keypad_field == KeypadField::Include && (is_keypad || !readable);
+        let kind = self.kind_field == KindField::Include;
+        let len = 1 + usize::from(!omit_modifiers) + usize::from(keypad) + usize::from(kind);
+        let mut state = serializer.serialize_struct("SerDeConfigKeyEvent", len)?;
+        state.serialize_field(
+            "code",
+            &CodeField {
+                code: &event.code,
+                codec: self,
+            },
+        )?;
+        if omit_modifiers {
+            state.skip_field("modifiers")?;
+        } else {
+            state.serialize_field(
+                "modifiers",
+                &ModifiersField {
+                    modifiers: &event.modifiers,
+                    codec: self,
+                },
+            )?;
+        }
+        if keypad {
+            state.serialize_field("keypad", &is_keypad)?;
+        } else {
+            state.skip_field("keypad")?;
+        }
+        if kind {
+            state.serialize_field("kind", &KindValue(&event.kind))?;
+        } else {
+            state.skip_field("kind")?;
+        }
+        state.end()
+    }
+
+    /// A format which is not self-describing like bincode gets the fields in the order
+    /// they are written, a self-describing one also the compact form like "CTRL+a".
+    pub fn deserialize<'de, D>(&self, deserializer: D) -> Result<KeyEvent, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = KeyEventVisitor { codec: self };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_struct("SerDeConfigKeyEvent", self.fields(), visitor)
+        }
+    }
+
+    /// Writes the compact form like "CONTROL+a" with the options of this codec
+    /// for the normalization and the modifier keywords. Without modifiers it is only the key code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key code has no readable text like a media key.
+    pub fn to_string(&self, event: &KeyEvent) -> Result<String, KeyParseError> {
+        let event = self.normalize(event);
+        let code = self.code_text(&event.code)?;
+        if event.modifiers.is_empty() {
+            return Ok(code.into_owned());
+        }
+        let mut text = self
+            .modifier_keywords(&event.modifiers)
+            .join(key_modifier::SEPERATOR);
+        text.push_str(key_modifier::SEPERATOR);
+        text.push_str(&code);
+        Ok(text)
+    }
+
+    /// Reads the compact form like "CONTROL+a" with the parse mode and the default kind
+    /// of this codec.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key code or one of the modifiers is not valid.
+    pub fn from_str(&self, text: &str) -> Result<KeyEvent, KeyParseError> {
+        let mut event = compact::key_event_from_str_with(
+            trim_config_text(text),
+            self.parse_mode,
+            self.keyword_fallback(),
+        )?;
+        event.kind = self.default_kind;
+        Ok(event)
+    }
+
+    fn code_text(&self, code: &KeyCode) -> Result<Cow<'static, str>, KeyParseError> {
+        match self
+            .custom_key_codes
+            .and_then(|custom| (custom.write)(code))
+        {
+            Some(name) => Ok(Cow::Owned(name)),
+            None => key_code::key_code_to_text(code),
+        }
+    }
+
+    fn keyword_fallback(&self) -> Option<key_code::KeywordFallback> {
+        self.custom_key_codes.map(|custom| custom.parse)
+    }
+
+    /// Fields of a key event with the options for keypad and kind in the order they are written.
+    fn fields(&self) -> &'static [&'static str] {
+        match (self.keypad_field, self.kind_field) {
+            (KeypadField::Omit, KindField::Omit) => FIELDS,
+            (KeypadField::Include, KindField::Omit) => FIELDS_WITH_KEYPAD,
+            (KeypadField::Omit, KindField::Include) => FIELDS_WITH_KIND,
+            (KeypadField::Include, KindField::Include) => FIELDS_WITH_KEYPAD_AND_KIND,
+        }
+    }
+
+    fn normalize(&self, event: &KeyEvent) -> KeyEvent {
+        self.back_tab_normalization
+            .normalize(&self.shift_normalization.normalize(event))
+    }
+
+    fn modifier_keywords(&self, modifiers: &KeyModifiers) -> Vec<Cow<'static, str>> {
+        let mut keywords = key_modifier::bits_to_strs(modifiers);
+        self.modifier_sort.sort(&mut keywords);
+        keywords
+            .into_iter()
+            .map(|keyword| {
+                let name = self.modifier_naming.name(keyword);
+                match self.modifier_case {
+                    ModifierCase::Uppercase => name,
+                    ModifierCase::Lowercase => Cow::Owned(name.to_ascii_lowercase()),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Assembles a [`KeyEventCodec`]. Every option not set stays at its default.
+#[derive(Debug, Clone, Copy)]
+pub struct KeyEventCodecBuilder {
+    codec: KeyEventCodec,
+}
+
+impl KeyEventCodecBuilder {
+    pub const fn modifiers_style(mut self, style: ModifiersStyle) -> Self {
+        self.codec.modifiers_style = style;
+        self
+    }
+
+    pub const fn shift_normalization(mut self, normalization: ShiftNormalization) -> Self {
+        self.codec.shift_normalization = normalization;
+        self
+    }
+
+    pub const fn back_tab_normalization(mut self, normalization: BackTabNormalization) -> Self {
+        self.codec.back_tab_normalization = normalization;
+        self
+    }
+
+    pub const fn modifier_case(mut self, case: ModifierCase) -> Self {
+        self.codec.modifier_case = case;
+        self
+    }
+
+    pub const fn modifier_naming(mut self, naming: ModifierNaming) -> Self {
+        self.codec.modifier_naming = naming;
+        self
+    }
+
+    pub const fn modifier_sort(mut self, sort: ModifierSort) -> Self {
+        self.codec.modifier_sort = sort;
+        self
+    }
+
+    pub const fn parse_mode(mut self, mode: ParseMode) -> Self {
+        self.codec.parse_mode = mode;
+        self
+    }
+
+    pub const fn none_modifiers(mut self, none_modifiers: NoneModifiers) -> Self {
+        self.codec.none_modifiers = none_modifiers;
+        self
+    }
+
+    pub const fn unknown_fields(mut self, unknown_fields: UnknownFields) -> Self {
+        self.codec.unknown_fields = unknown_fields;
+        self
+    }
+
+    pub const fn blank_modifiers(mut self, blank: BlankModifiers) -> Self {
+        self.codec.blank_modifiers = blank;
+        self
+    }
+
+    pub const fn empty_modifiers(mut self, empty: EmptyModifiers) -> Self {
+        self.codec.empty_modifiers = empty;
+        self
+    }
+
+    /// Whether the kind of a key event is written and read, see [`KindField`].
+    pub const fn kind_field(mut self, kind_field: KindField) -> Self {
+        self.codec.kind_field = kind_field;
+        self
+    }
+
+    /// Whether the KEYPAD state of a key event is written and read, see [`KeypadField`].
+    pub const fn keypad_field(mut self, keypad_field: KeypadField) -> Self {
+        self.codec.keypad_field = keypad_field;
+        self
+    }
+
+    /// Kind of a deserialized key event which has no kind field like `{ "code": "a" }`
+    /// or is in the compact form. With [`KindField::Omit`] it is the kind of every
+    /// deserialized key event. Defaults to [`KeyEventKind::Press`].
+    pub const fn default_kind(mut self, kind: KeyEventKind) -> Self {
+        self.codec.default_kind = kind;
+        self
+    }
+
+    /// Kinds which a deserialized key event may have in its kind field
+    /// with [`KindField::Include`]. Any other kind is an error like "Release events are not supported by this application".
+    /// Defaults to all kinds.
+    pub const fn allowed_kinds(mut self, kinds: &'static [KeyEventKind]) -> Self {
+        self.codec.allowed_kinds = kinds;
+        self
+    }
+
+    /// Key names of the app on top of the built-in keywords, see [`CustomKeyCodes`].
+    pub const fn custom_key_codes(mut self, custom: CustomKeyCodes) -> Self {
+        self.codec.custom_key_codes = Some(custom);
+        self
+    }
+
+    pub const fn build(self) -> KeyEventCodec {
+        self.codec
+    }
+}
+
+struct KindValue<'a>(&'a KeyEventKind);
+
+impl Serialize for KindValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serde_key_event_kind::serialize(self.0, serializer)
+    }
+}
+
+struct CodeField<'a> {
+    code: &'a KeyCode,
+    codec: &'a KeyEventCodec,
+}
+
+impl Serialize for CodeField<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let text = self
+            .codec
+            .code_text(self.code)
+            .map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
+
+struct ModifiersField<'a> {
+    modifiers: &'a KeyModifiers,
+    codec: &'a KeyEventCodec,
+}
+
+impl Serialize for ModifiersField<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let codec = self.codec;
+        // Only the joined keywords are read back by a format which is not self-describing.
+        let style = if serializer.is_human_readable() {
+            codec.modifiers_style
+        } else {
+            ModifiersStyle::Joined
+        };
+        if codec.empty_modifiers == EmptyModifiers::AsEmpty && self.modifiers.is_empty() {
+            return match style {
+                ModifiersStyle::Joined => serializer.serialize_str(""),
+                ModifiersStyle::Array => serializer.collect_seq(std::iter::empty::<&str>()),
+                ModifiersStyle::Flags => serializer.collect_map(std::iter::empty::<(&str, bool)>()),
+            };
+        }
+        if style == ModifiersStyle::Joined
+            && codec.modifier_naming == ModifierNaming::Screaming
+            && codec.modifier_case == ModifierCase::Uppercase
+            && codec.modifier_sort == ModifierSort::Canonical
+        {
+            return serde_key_modifier::serialize(self.modifiers, serializer);
+        }
+        let keywords = codec.modifier_keywords(self.modifiers);
+        match style {
+            ModifiersStyle::Joined => {
+                serializer.serialize_str(&keywords.join(key_modifier::SEPERATOR))
+            }
+            ModifiersStyle::Array => serializer.collect_seq(keywords),
+            ModifiersStyle::Flags if self.modifiers.is_empty() => {
+                serializer.collect_map(std::iter::empty::<(&str, bool)>())
+            }
+            ModifiersStyle::Flags => {
+                serializer.collect_map(keywords.into_iter().map(|keyword| (keyword, true)))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Code,
+    Modifiers,
+    Keypad,
+    Kind,
+    Unknown(String),
+}
+
+const FIELDS: &[&str] = &["code", "modifiers"];
+const FIELDS_WITH_KEYPAD: &[&str] = &["code", "modifiers", "keypad"];
+const FIELDS_WITH_KIND: &[&str] = &["code", "modifiers", "kind"];
+const FIELDS_WITH_KEYPAD_AND_KIND: &[&str] = &["code", "modifiers", "keypad", "kind"];
+
+struct KeyEventVisitor<'a> {
+    codec: &'a KeyEventCodec,
+}
+
+impl KeyEventVisitor<'_> {
+    fn skip_unknown_field<'de, A>(&self, map: &mut A, name: &str) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match self.codec.unknown_fields {
+            UnknownFields::Ignore => map.next_value::<IgnoredAny>().map(|_| ()),
+            UnknownFields::Deny => Err(de::Error::unknown_field(name, self.codec.fields())),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for KeyEventVisitor<'_> {
+    type Value = KeyEvent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(
+            "a key event with a code and optional modifiers or its compact form like CTRL+a",
+        )
+    }
+
+    fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.codec.from_str(text).map_err(de::Error::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let codec = self.codec;
+        let code = seq
+            .next_element_seed(CodeSeed { codec })?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let modifiers = seq
+            .next_element_seed(ModifiersSeed { codec })?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let keypad = if codec.keypad_field == KeypadField::Include {
+            seq.next_element::<bool>()?
+                .ok_or_else(|| de::Error::invalid_length(2, &self))?
+        } else {
+            false
+        };
+        let kind = if codec.kind_field == KindField::Include {
+            let index = 2 + usize::from(codec.keypad_field == KeypadField::Include);
+            seq.next_element_seed(KindSeed {
+                allowed: codec.allowed_kinds,
+            })?
+            .ok_or_else(|| de::Error::invalid_length(index, &self))?
+        } else {
+            codec.default_kind
+        };
+        let state = if keypad {
+            KeyEventState::KEYPAD
+        } else {
+            KeyEventState::NONE
+        };
+        Ok(KeyEvent::new_with_kind_and_state(
+            code, modifiers, kind, state,
+        ))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut code = None;
+        let mut modifiers = None;
+        let mut keypad = None;
+        let mut kind = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Code => {
+                    if code.is_some() {
+                        return Err(de::Error::duplicate_field("code"));
+                    }
+                    code = Some(map.next_value_seed(CodeSeed { codec: self.codec })?);
+                }
+                Field::Modifiers => {
+                    if modifiers.is_some() {
+                        return Err(de::Error::duplicate_field("modifiers"));
+                    }
+                    modifiers = Some(map.next_value_seed(ModifiersSeed { codec: self.codec })?);
+                }
+                Field::Keypad if self.codec.keypad_field == KeypadField::Include => {
+                    if keypad.is_some() {
+                        return Err(de::Error::duplicate_field("keypad"));
+                    }
+                    keypad = Some(map.next_value::<bool>()?);
+                }
+                Field::Kind if self.codec.kind_field == KindField::Include => {
+                    if kind.is_some() {
+                        return Err(de::Error::duplicate_field("kind"));
+                    }
+                    kind = Some(map.next_value_seed(KindSeed {
+                        allowed: self.codec.allowed_kinds,
+                    })?);
+                }
+                Field::Keypad => self.skip_unknown_field(&mut map, "keypad")?,
+                Field::Kind => self.skip_unknown_field(&mut map, "kind")?,
+                Field::Unknown(name) => self.skip_unknown_field(&mut map, &name)?,
+            }
+        }
+
+        let code = code.ok_or_else(|| de::Error::missing_field("code"))?;
+        let modifiers = modifiers.unwrap_or(KeyModifiers::NONE);
+        let state = if keypad.unwrap_or(false) {
+            KeyEventState::KEYPAD
+        } else {
+            KeyEventState::NONE
+        };
+        Ok(KeyEvent::new_with_kind_and_state(
+            code,
+            modifiers,
+            kind.unwrap_or(self.codec.default_kind),
+            state,
+        ))
+    }
+}
+
+struct CodeSeed<'a> {
+    codec: &'a KeyEventCodec,
+}
+
+impl<'de> DeserializeSeed<'de> for CodeSeed<'_> {
+    type Value = KeyCode;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_key_code::deserialize_with(
+            deserializer,
+            self.codec.parse_mode,
+            self.codec.keyword_fallback(),
+        )
+    }
+}
+
+struct KindSeed {
+    allowed: &'static [KeyEventKind],
+}
+
+impl<'de> DeserializeSeed<'de> for KindSeed {
+    type Value = KeyEventKind;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let kind = serde_key_event_kind::deserialize(deserializer)?;
+        if self.allowed.contains(&kind) {
+            Ok(kind)
+        } else {
+            Err(de::Error::custom(KeyParseError::DisallowedEventKind(kind)))
+        }
+    }
+}
+
+struct ModifiersSeed<'a> {
+    codec: &'a KeyEventCodec,
+}
+
+impl<'de> DeserializeSeed<'de> for ModifiersSeed<'_> {
+    type Value = KeyModifiers;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let blank = match self.codec.empty_modifiers {
+            EmptyModifiers::AsNone => self.codec.blank_modifiers,
+            EmptyModifiers::AsEmpty => BlankModifiers::AsNone,
+        };
+        serde_key_modifier::deserialize_with(deserializer, self.codec.parse_mode, blank)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::{key_event_to_string, SerDeConfigKeyEvent};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    struct KeyBoard {
+        #[serde(serialize_with = "serialize_key", deserialize_with = "deserialize_key")]
+        move_up: KeyEvent,
+        #[serde(serialize_with = "serialize_key", deserialize_with = "deserialize_key")]
+        move_down: KeyEvent,
+    }
+
+    static ARRAY_CODEC: KeyEventCodec = KeyEventCodec::builder()
+        .modifiers_style(ModifiersStyle::Array)
+        .build();
+
+    fn serialize_key<S: Serializer>(event: &KeyEvent, serializer: S) -> Result<S::Ok, S::Error> {
+        ARRAY_CODEC.serialize(event, serializer)
+    }
+
+    fn deserialize_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<KeyEvent, D::Error> {
+        ARRAY_CODEC.deserialize(deserializer)
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    struct BinaryBinding {
+        #[serde(
+            serialize_with = "serialize_binary",
+            deserialize_with = "deserialize_binary"
+        )]
+        key: KeyEvent,
+    }
+
+    static BINARY_CODEC: KeyEventCodec = KeyEventCodec::builder()
+        .modifiers_style(ModifiersStyle::Flags)
+        .none_modifiers(NoneModifiers::Omit)
+        .kind_field(KindField::Include)
+        .keypad_field(KeypadField::Include)
+        .build();
+
+    fn serialize_binary<S: Serializer>(event: &KeyEvent, serializer: S) -> Result<S::Ok, S::Error> {
+        BINARY_CODEC.serialize(event, serializer)
+    }
+
+    fn deserialize_binary<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<KeyEvent, D::Error> {
+        BINARY_CODEC.deserialize(deserializer)
+    }
+
+    fn input() -> KeyBoard {
+        KeyBoard {
+            move_up: KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            move_down: KeyEvent::new(
+                KeyCode::Char('j'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
+            ),
+        }
+    }
+
+    #[test]
+    fn should_round_trip_in_format_which_is_not_self_describing() {
+        let key_board = KeyBoard {
+            move_up: KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
+            ),
+            move_down: KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+        };
+        let bytes = bincode::serialize(&key_board).unwrap();
+        assert_eq!(key_board, bincode::deserialize(&bytes).unwrap());
+
+        assert_case(KeyEvent::new_with_kind_and_state(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+            KeyEventState::KEYPAD,
+        ));
+        assert_case(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL));
+        fn assert_case(key: KeyEvent) {
+            let input = BinaryBinding { key };
+            let bytes = bincode::serialize(&input).unwrap();
+            let actual: BinaryBinding = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(input, actual);
+        }
+    }
+    #[test]
+    fn should_serialize_modifiers_as_array() {
+        let actual = serde_json::to_string(&input()).unwrap();
+        assert_eq!(
+            r#"{"move_up":{"code":"Up","modifiers":["NONE"]},"move_down":{"code":"j","modifiers":["CONTROL","ALT"]}}"#,
+            actual
+        );
+        let actual = ron::to_string(&input()).unwrap();
+        assert_eq!(
+            r#"(move_up:(code:"Up",modifiers:["NONE"]),move_down:(code:"j",modifiers:["CONTROL","ALT"]))"#,
+            actual
+        );
+    }
+    #[test]
+    fn should_round_trip_array_modifiers() {
+        let text = serde_json::to_string(&input()).unwrap();
+        let actual: KeyBoard = serde_json::from_str(&text).unwrap();
+        assert_eq!(input(), actual);
+    }
+    #[test]
+    fn should_normalize_letter_with_shift() {
+        let uppercase = KeyEventCodec::builder()
+            .shift_normalization(ShiftNormalization::Uppercase)
+            .build();
+        let with_shift = KeyEventCodec::builder()
+            .shift_normalization(ShiftNormalization::LowercaseWithShift)
+            .build();
+        let inputs = [
+            KeyEvent::new(KeyCode::Char('A'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT),
+        ];
+        for input in inputs {
+            assert_eq!(
+                r#"{"code":"A","modifiers":"NONE"}"#,
+                to_json(&uppercase, &input)
+            );
+            assert_eq!(
+                r#"{"code":"a","modifiers":"SHIFT"}"#,
+                to_json(&with_shift, &input)
+            );
+        }
+    }
+    #[test]
+    fn should_normalize_shift_tab() {
+        let back_tab = KeyEventCodec::builder()
+            .back_tab_normalization(BackTabNormalization::BackTab)
+            .build();
+        let tab_with_shift = KeyEventCodec::builder()
+            .back_tab_normalization(BackTabNormalization::TabWithShift)
+            .build();
+        let inputs = [
+            KeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Tab, KeyModifiers::SHIFT),
+        ];
+        for input in inputs {
+            assert_eq!(
+                r#"{"code":"BackTab","modifiers":"NONE"}"#,
+                to_json(&back_tab, &input)
+            );
+            assert_eq!(
+                r#"{"code":"Tab","modifiers":"SHIFT"}"#,
+                to_json(&tab_with_shift, &input)
+            );
+        }
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(
+            r#"{"code":"Tab","modifiers":"NONE"}"#,
+            to_json(&back_tab, &tab)
+        );
+    }
+    #[test]
+    fn should_convert_compact_form_with_options() {
+        let codec = KeyEventCodec::builder()
+            .modifier_case(ModifierCase::Lowercase)
+            .modifier_naming(ModifierNaming::MacStyle)
+            .modifier_sort(ModifierSort::Alphabetical)
+            .shift_normalization(ShiftNormalization::LowercaseWithShift)
+            .parse_mode(ParseMode::Strict)
+            .default_kind(KeyEventKind::Release)
+            .build();
+        let input = KeyEvent::new(
+            KeyCode::Char('S'),
+            KeyModifiers::SUPER | KeyModifiers::CONTROL,
+        );
+        let text = codec.to_string(&input).unwrap();
+        assert_eq!("control+shift+command+s", text);
+        let back = codec.from_str(&text).unwrap();
+        assert_eq!(
+            KeyEvent::new_with_kind(
+                KeyCode::Char('s'),
+                KeyModifiers::SUPER | KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+                KeyEventKind::Release
+            ),
+            back
+        );
+        assert_eq!(
+            "Up",
+            codec
+                .to_string(&KeyEvent::new(KeyCode::Up, KeyModifiers::NONE))
+                .unwrap()
+        );
+        assert!(codec.from_str("CONTROL, ALT+a").is_err());
+        assert_eq!(
+            key_event_to_string(&input).unwrap(),
+            KeyEventCodec::default().to_string(&input).unwrap()
+        );
+    }
+    #[test]
+    fn should_sort_modifiers() {
+        let input = KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::SHIFT | KeyModifiers::CONTROL | KeyModifiers::ALT,
+        );
+        assert_case(ModifierSort::Alphabetical, r#""ALT+CONTROL+SHIFT""#);
+        assert_case(ModifierSort::Canonical, r#""SHIFT+CONTROL+ALT""#);
+        fn control_first(left: &str, right: &str) -> Ordering {
+            (left != "CONTROL").cmp(&(right != "CONTROL"))
+        }
+        assert_case(
+            ModifierSort::Custom(control_first),
+            r#""CONTROL+SHIFT+ALT""#,
+        );
+        assert_eq!(
+            to_json(&KeyEventCodec::default(), &input),
+            to_json(
+                &KeyEventCodec::builder()
+                    .modifier_sort(ModifierSort::Canonical)
+                    .build(),
+                &input
+            )
+        );
+        fn assert_case(sort: ModifierSort, modifiers: &str) {
+            let input = KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::SHIFT | KeyModifiers::CONTROL | KeyModifiers::ALT,
+            );
+            let codec = KeyEventCodec::builder().modifier_sort(sort).build();
+            let expected = format!(r#"{{"code":"a","modifiers":{}}}"#, modifiers);
+            assert_eq!(expected, to_json(&codec, &input));
+            let back = codec
+                .deserialize(&mut serde_json::Deserializer::from_str(&expected))
+                .unwrap();
+            assert_eq!(input, back);
+        }
+    }
+    #[test]
+    fn should_resolve_custom_key_codes() {
+        const LEADER: KeyCode = KeyCode::Char('\u{e000}');
+        const CODEC: KeyEventCodec = KeyEventCodec::builder()
+            .custom_key_codes(CustomKeyCodes {
+                parse: |name| (name == "Leader").then_some(LEADER),
+                write: |code| (*code == LEADER).then(|| "Leader".to_string()),
+            })
+            .build();
+        let leader = KeyEvent::new(LEADER, KeyModifiers::CONTROL);
+        for input in [
+            r#"{"code":"Leader","modifiers":"CONTROL"}"#,
+            r#""CONTROL+Leader""#,
+            r#""Leader+CONTROL""#,
+        ] {
+            let actual = CODEC
+                .deserialize(&mut serde_json::Deserializer::from_str(input))
+                .unwrap();
+            assert_eq!(leader, actual, "{}", input);
+        }
+        assert_eq!(
+            r#"{"code":"Leader","modifiers":"CONTROL"}"#,
+            to_json(&CODEC, &leader)
+        );
+        assert_eq!("CONTROL+Leader", CODEC.to_string(&leader).unwrap());
+        assert_eq!(
+            r#"{"code":"Up","modifiers":"NONE"}"#,
+            to_json(&CODEC, &KeyEvent::new(KeyCode::Up, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            Err(KeyParseError::UnknownKeyword("Follower".to_string())),
+            CODEC.from_str("CONTROL+Follower")
+        );
+        assert!(CODEC
+            .deserialize(&mut serde_json::Deserializer::from_str(
+                r#"{"code":"Follower"}"#
+            ))
+            .is_err());
+    }
+    #[test]
+    fn should_deny_kind_which_is_not_allowed() {
+        const CODEC: KeyEventCodec = KeyEventCodec::builder()
+            .kind_field(KindField::Include)
+            .allowed_kinds(&[KeyEventKind::Press])
+            .build();
+        let actual = CODEC
+            .deserialize(&mut serde_json::Deserializer::from_str(
+                r#"{"code":"a","kind":"Press"}"#,
+            ))
+            .unwrap();
+        assert_eq!(KeyEventKind::Press, actual.kind);
+        let actual = CODEC
+            .deserialize(&mut serde_json::Deserializer::from_str(r#""CTRL+a""#))
+            .unwrap();
+        assert_eq!(KeyEventKind::Press, actual.kind);
+        let error = CODEC
+            .deserialize(&mut serde_json::Deserializer::from_str(
+                r#"{"code":"a","kind":"Release"}"#,
+            ))
+            .unwrap_err();
+        assert_eq!(
+            "Release events are not supported by this application at line 1 column 29",
+            error.to_string()
+        );
+    }
+    #[test]
+    fn should_fill_in_configured_default_kind() {
+        let codec = KeyEventCodec::builder()
+            .kind_field(KindField::Include)
+            .default_kind(KeyEventKind::Release)
+            .build();
+        assert_case(
+            &codec,
+            r#"{"code":"a","modifiers":"CONTROL"}"#,
+            KeyEventKind::Release,
+        );
+        assert_case(&codec, r#""CTRL+a""#, KeyEventKind::Release);
+        assert_case(
+            &codec,
+            r#"{"code":"a","modifiers":"CONTROL","kind":"Press"}"#,
+            KeyEventKind::Press,
+        );
+        assert_case(
+            &KeyEventCodec::default(),
+            r#"{"code":"a","modifiers":"CONTROL"}"#,
+            KeyEventKind::Press,
+        );
+        fn assert_case(codec: &KeyEventCodec, input: &str, kind: KeyEventKind) {
+            let actual = codec
+                .deserialize(&mut serde_json::Deserializer::from_str(input))
+                .unwrap();
+            assert_eq!(
+                KeyEvent::new_with_kind(KeyCode::Char('a'), KeyModifiers::CONTROL, kind),
+                actual
+            );
+            assert_eq!(kind, actual.kind);
+        }
+    }
+    #[test]
+    fn should_round_trip_kind_only_if_included() {
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        );
+        let with_kind = KeyEventCodec::builder()
+            .kind_field(KindField::Include)
+            .build();
+        let text = to_json(&with_kind, &release);
+        assert_eq!(r#"{"code":"a","modifiers":"NONE","kind":"Release"}"#, text);
+        let back = with_kind
+            .deserialize(&mut serde_json::Deserializer::from_str(&text))
+            .unwrap();
+        assert_eq!(KeyEventKind::Release, back.kind);
+        assert_eq!(
+            r#"{"code":"a","modifiers":"NONE","kind":"Press"}"#,
+            to_json(
+                &with_kind,
+                &KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)
+            )
+        );
+
+        let text = r#"{"code":"a","kind":"Release"}"#;
+        let back = KeyEventCodec::default()
+            .deserialize(&mut serde_json::Deserializer::from_str(text))
+            .unwrap();
+        assert_eq!(KeyEventKind::Press, back.kind);
+        let error = KeyEventCodec::builder()
+            .unknown_fields(UnknownFields::Deny)
+            .build()
+            .deserialize(&mut serde_json::Deserializer::from_str(text))
+            .unwrap_err();
+        assert_eq!(
+            "unknown field `kind`, expected `code` or `modifiers` at line 1 column 18",
+            error.to_string()
+        );
+    }
+    #[test]
+    fn should_carry_keypad_state_if_included() {
+        let codec = KeyEventCodec::builder()
+            .keypad_field(KeypadField::Include)
+            .build();
+        let input = KeyEvent::new_with_kind_and_state(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            KeyEventKind::Press,
+            KeyEventState::KEYPAD,
+        );
+        let text = to_json(&codec, &input);
+        assert_eq!(r#"{"code":"Enter","modifiers":"NONE","keypad":true}"#, text);
+        let back = codec
+            .deserialize(&mut serde_json::Deserializer::from_str(&text))
+            .unwrap();
+        assert_eq!(KeyEventState::KEYPAD, back.state);
+        assert_eq!(input, back);
+        let without_keypad = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            r#"{"code":"Enter","modifiers":"NONE"}"#,
+            to_json(&codec, &without_keypad)
+        );
+
+        assert_eq!(
+            r#"{"code":"Enter","modifiers":"NONE"}"#,
+            to_json(&KeyEventCodec::default(), &input)
+        );
+        let back = KeyEventCodec::default()
+            .deserialize(&mut serde_json::Deserializer::from_str(&text))
+            .unwrap();
+        assert_eq!(KeyEventState::NONE, back.state);
+    }
+    #[test]
+    fn should_keep_shift_for_chars_without_case() {
+        let codec = KeyEventCodec::builder()
+            .shift_normalization(ShiftNormalization::Uppercase)
+            .build();
+        let input = KeyEvent::new(KeyCode::Char('1'), KeyModifiers::SHIFT);
+        assert_eq!(
+            r#"{"code":"1","modifiers":"SHIFT"}"#,
+            to_json(&codec, &input)
+        );
+        let input = KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT);
+        assert_eq!(
+            r#"{"code":"Up","modifiers":"SHIFT"}"#,
+            to_json(&codec, &input)
+        );
+    }
+    #[test]
+    fn should_serialize_lowercase_modifiers() {
+        let codec = KeyEventCodec::builder()
+            .modifier_case(ModifierCase::Lowercase)
+            .build();
+        let inputs = [
+            KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            ),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT | KeyModifiers::SUPER),
+        ];
+        let actual: Vec<String> = inputs.iter().map(|input| to_json(&codec, input)).collect();
+        insta::assert_display_snapshot!(actual.join("\n"));
+        for (input, text) in inputs.iter().zip(actual) {
+            let back: KeyEvent = codec
+                .deserialize(&mut serde_json::Deserializer::from_str(&text))
+                .unwrap();
+            assert_eq!(*input, back);
+        }
+    }
+    #[test]
+    fn should_omit_none_modifiers() {
+        let codec = KeyEventCodec::builder()
+            .none_modifiers(NoneModifiers::Omit)
+            .build();
+        let inputs = [
+            KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE),
+        ];
+        let actual: Vec<String> = inputs.iter().map(|input| to_json(&codec, input)).collect();
+        insta::assert_display_snapshot!(actual.join("\n"));
+        for (input, text) in inputs.iter().zip(actual) {
+            let back: KeyEvent = codec
+                .deserialize(&mut serde_json::Deserializer::from_str(&text))
+                .unwrap();
+            assert_eq!(*input, back);
+        }
+    }
+    #[test]
+    fn should_keep_none_modifiers_by_default() {
+        let input = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(
+            r#"{"code":"Up","modifiers":"NONE"}"#,
+            to_json(&KeyEventCodec::default(), &input)
+        );
+    }
+    #[test]
+    fn should_deny_unknown_fields_if_configured() {
+        let strict = KeyEventCodec::builder()
+            .unknown_fields(UnknownFields::Deny)
+            .build();
+        let text = r#"{"code":"a","modifers":"ALT"}"#;
+        let actual = strict
+            .deserialize(&mut serde_json::Deserializer::from_str(text))
+            .unwrap_err();
+        assert_eq!(
+            "unknown field `modifers`, expected `code` or `modifiers` at line 1 column 22",
+            actual.to_string()
+        );
+        let actual = KeyEventCodec::default()
+            .deserialize(&mut serde_json::Deserializer::from_str(text))
+            .unwrap();
+        assert_eq!(
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+            actual
+        );
+    }
+    #[test]
+    fn should_read_blank_modifiers_as_configured() {
+        let as_none = KeyEventCodec::builder()
+            .blank_modifiers(BlankModifiers::AsNone)
+            .build();
+        for text in [
+            r#"{"code":"a","modifiers":""}"#,
+            r#"{"code":"a","modifiers":" "}"#,
+        ] {
+            let actual = as_none
+                .deserialize(&mut serde_json::Deserializer::from_str(text))
+                .unwrap();
+            assert_eq!(
+                KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+                actual
+            );
+            let actual = KeyEventCodec::default()
+                .deserialize(&mut serde_json::Deserializer::from_str(text))
+                .unwrap_err();
+            assert!(actual
+                .to_string()
+                .starts_with("Need to provide at least keyword for the key modifier"));
+        }
+    }
+    #[test]
+    fn should_round_trip_modifiers_as_flags() {
+        let codec = KeyEventCodec::builder()
+            .modifiers_style(ModifiersStyle::Flags)
+            .build();
+        let input = KeyEvent::new(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::ALT);
+        let text = to_json(&codec, &input);
+        assert_eq!(
+            r#"{"code":"Up","modifiers":{"CONTROL":true,"ALT":true}}"#,
+            text
+        );
+        let back = codec
+            .deserialize(&mut serde_json::Deserializer::from_str(&text))
+            .unwrap();
+        assert_eq!(input, back);
+        let none = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(r#"{"code":"Up","modifiers":{}}"#, to_json(&codec, &none));
+    }
+    #[test]
+    fn should_read_modifiers_from_flags() {
+        assert_case(
+            r#"{ "ctrl": true, "alt": true }"#,
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        );
+        assert_case(r#"{ "ctrl": true, "alt": false }"#, KeyModifiers::CONTROL);
+        assert_case(r#"{ "shift": false, "alt": false }"#, KeyModifiers::NONE);
+        assert_case(r#"{}"#, KeyModifiers::NONE);
+        fn assert_case(modifiers: &str, expected: KeyModifiers) {
+            let text = format!(r#"{{"code":"a","modifiers":{}}}"#, modifiers);
+            let actual = KeyEventCodec::default()
+                .deserialize(&mut serde_json::Deserializer::from_str(&text))
+                .unwrap();
+            assert_eq!(KeyEvent::new(KeyCode::Char('a'), expected), actual);
+        }
+        for invalid in [r#"{ "altt": true }"#, r#"{ "alt": 1 }"#] {
+            let text = format!(r#"{{"code":"a","modifiers":{}}}"#, invalid);
+            assert!(KeyEventCodec::default()
+                .deserialize(&mut serde_json::Deserializer::from_str(&text))
+                .is_err());
+        }
+    }
+    #[test]
+    fn should_write_empty_modifiers_as_configured() {
+        let input = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(
+            r#"{"code":"Up","modifiers":"NONE"}"#,
+            to_json(&KeyEventCodec::default(), &input)
+        );
+        let as_empty = KeyEventCodec::builder()
+            .empty_modifiers(EmptyModifiers::AsEmpty)
+            .build();
+        assert_eq!(
+            r#"{"code":"Up","modifiers":""}"#,
+            to_json(&as_empty, &input)
+        );
+        let as_empty_array = KeyEventCodec::builder()
+            .empty_modifiers(EmptyModifiers::AsEmpty)
+            .modifiers_style(ModifiersStyle::Array)
+            .build();
+        assert_eq!(
+            r#"{"code":"Up","modifiers":[]}"#,
+            to_json(&as_empty_array, &input)
+        );
+        let with_modifiers = KeyEvent::new(KeyCode::Up, KeyModifiers::ALT);
+        assert_eq!(
+            r#"{"code":"Up","modifiers":"ALT"}"#,
+            to_json(&as_empty, &with_modifiers)
+        );
+    }
+    #[test]
+    fn should_read_empty_and_none_modifiers_alike() {
+        let as_empty = KeyEventCodec::builder()
+            .empty_modifiers(EmptyModifiers::AsEmpty)
+            .build();
+        for text in [
+            r#"{"code":"Up","modifiers":""}"#,
+            r#"{"code":"Up","modifiers":"NONE"}"#,
+            r#"{"code":"Up","modifiers":[]}"#,
+            r#"{"code":"Up"}"#,
+        ] {
+            let actual = as_empty
+                .deserialize(&mut serde_json::Deserializer::from_str(text))
+                .unwrap();
+            assert_eq!(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE), actual);
+        }
+    }
+    #[test]
+    fn should_serialize_with_modifier_naming() {
+        assert_case(ModifierNaming::Screaming, "SHIFT+ALT+SUPER");
+        assert_case(ModifierNaming::TitleCase, "Shift+Alt+Super");
+        assert_case(ModifierNaming::MacStyle, "Shift+Option+Command");
+        assert_case(ModifierNaming::WindowsStyle, "Shift+Alt+Win");
+        let platform = if cfg!(target_os = "macos") {
+            "Shift+Option+Command"
+        } else if cfg!(target_os = "windows") {
+            "Shift+Alt+Win"
+        } else {
+            "Shift+Alt+Super"
+        };
+        assert_case(ModifierNaming::Platform, platform);
+        fn assert_case(naming: ModifierNaming, expected: &str) {
+            let codec = KeyEventCodec::builder().modifier_naming(naming).build();
+            let input = KeyEvent::new(
+                KeyCode::Char('p'),
+                KeyModifiers::SUPER | KeyModifiers::SHIFT | KeyModifiers::ALT,
+            );
+            let text = to_json(&codec, &input);
+            assert_eq!(
+                format!(r#"{{"code":"p","modifiers":"{}"}}"#, expected),
+                text
+            );
+            let back: KeyEvent = codec
+                .deserialize(&mut serde_json::Deserializer::from_str(&text))
+                .unwrap();
+            assert_eq!(input, back);
+        }
+        let none = KeyEventCodec::builder()
+            .modifier_naming(ModifierNaming::TitleCase)
+            .build();
+        assert_eq!(
+            r#"{"code":"p","modifiers":"None"}"#,
+            to_json(
+                &none,
+                &KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE)
+            )
+        );
+    }
+    #[test]
+    fn should_apply_parse_mode() {
+        let lenient = KeyEventCodec::default();
+        let strict = KeyEventCodec::builder()
+            .parse_mode(ParseMode::Strict)
+            .build();
+        let input = r#"{"code":"a","modifiers":"ALT, CONTROL"}"#;
+        let actual = lenient
+            .deserialize(&mut serde_json::Deserializer::from_str(input))
+            .unwrap();
+        assert_eq!(
+            KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL
+            ),
+            actual
+        );
+        let actual = strict.deserialize(&mut serde_json::Deserializer::from_str(input));
+        assert!(actual.is_err());
+    }
+    #[test]
+    fn should_serialize_like_default_helper() {
+        let event = KeyEvent::new(KeyCode::Left, KeyModifiers::ALT | KeyModifiers::CONTROL);
+        let actual = to_json(&KeyEventCodec::default(), &event);
+        let mut expected = Vec::new();
+        SerDeConfigKeyEvent::serialize(&event, &mut serde_json::Serializer::new(&mut expected))
+            .unwrap();
+        assert_eq!(String::from_utf8(expected).unwrap(), actual);
+    }
+
+    fn to_json(codec: &KeyEventCodec, event: &KeyEvent) -> String {
+        let mut output = Vec::new();
+        codec
+            .serialize(event, &mut serde_json::Serializer::new(&mut output))
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+}
@@ -0,0 +1,395 @@
+//! Compact form of a key event as one text like "CONTROL+a".
+//!
+//! The modifiers come first, each followed by the separator "+". The key code is the last part.
+//! A key event without modifiers is only its key code like "Up".
+//! On parsing the key code may also come before or between the modifiers like "a+CTRL".
+//! Then the only part which is not a modifier keyword is the key code.
+//!
+//! A modifier like ALT is pressed together with the key code. A modifier key like "LeftAlt"
+//! is the key code of pressing the left ALT key on its own, which terminals with the kitty
+//! keyboard protocol report. In a key event it is only valid as the last part like "SHIFT+LeftAlt".
+//! The modifier ALT has no side, so "LeftAlt+x" is no key event. It is a
+//! [`SidedKeyEvent`](crate::SidedKeyEvent) instead: the key event "ALT+x" while LeftAlt is held.
+
+use crossterm::event::{KeyEvent, KeyModifiers};
+use std::borrow::Cow;
+
+use crate::key_text::key_code::{self, KeywordFallback};
+use crate::key_text::key_modifier;
+use crate::{BindingKey, KeyParseError, ParseMode};
+
+/// Converts a key event into its compact form like "CONTROL+ALT+a" without serde.
+///
+/// Kind and state of the key event are not part of the text.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::key_event_to_string;
+///
+/// let event = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::ALT | KeyModifiers::CONTROL);
+/// assert_eq!("CONTROL+ALT+a", key_event_to_string(&event).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the key code has no readable text like a media key.
+pub fn key_event_to_string(event: &KeyEvent) -> Result<String, KeyParseError> {
+    let code = key_code::key_code_to_text(&event.code)?;
+    Ok(join(&event.modifiers, &code))
+}
+
+/// Converts a key event into a stable text for keys of a cache like "SHIFT+CONTROL+A".
+///
+/// The text is the compact form and does not depend on the options of a codec.
+/// Like [`key_event_matches`](crate::key_event_matches) kind and state are ignored and
+/// the key event is normalized like a [`BindingKey`], so key events which match have the same text.
+/// Unlike [`key_event_to_string`] it never fails. A char without readable text is written as
+/// code point like "U+0001" and other key codes without a keyword like "Media(Play)".
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::canonical_key_event_key;
+///
+/// let event = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::SHIFT | KeyModifiers::CONTROL);
+/// assert_eq!("SHIFT+CONTROL+A", canonical_key_event_key(&event));
+/// ```
+pub fn canonical_key_event_key(event: &KeyEvent) -> String {
+    let key = BindingKey::from(event);
+    let code = key_code::key_code_to_text(&key.code())
+        .unwrap_or_else(|_| Cow::Owned(format!("{:?}", key.code())));
+    join(&key.modifiers(), &code)
+}
+
+fn join(modifiers: &KeyModifiers, code: &str) -> String {
+    if modifiers.is_empty() {
+        return code.to_string();
+    }
+
+    let mut text = key_modifier::bits_to_strs(modifiers).join(key_modifier::SEPERATOR);
+    text.push_str(key_modifier::SEPERATOR);
+    text.push_str(code);
+    text
+}
+
+/// Parses a key event from its compact form like "CTRL+a" without serde.
+///
+/// The key code may also come before the modifiers like "a+CTRL".
+/// The returned key event has the kind press and no state.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::key_event_from_str;
+///
+/// let event = key_event_from_str("CTRL+a").unwrap();
+/// assert_eq!(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL), event);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the key code or one of the modifiers is not valid.
+pub fn key_event_from_str(text: &str) -> Result<KeyEvent, KeyParseError> {
+    key_event_from_str_with(text, ParseMode::Lenient, None)
+}
+
+pub(crate) fn key_event_from_str_with(
+    text: &str,
+    mode: ParseMode,
+    fallback: Option<KeywordFallback>,
+) -> Result<KeyEvent, KeyParseError> {
+    let (modifiers, code) = split_modifiers(text);
+    if !modifiers.trim().is_empty() && key_modifier::is_keyword(code.trim()) {
+        return key_event_from_any_order(text, mode, fallback);
+    }
+    let code = key_code::parse_key_code_with_fallback(code.trim(), mode, fallback)?;
+    let modifiers = if modifiers.trim().is_empty() {
+        KeyModifiers::NONE
+    } else {
+        key_modifier::parse_key_modifier_with(modifiers, mode)?
+    };
+    Ok(KeyEvent::new(code, modifiers))
+}
+
+/// Splits the compact form into the text of the modifiers and the text of the key code.
+/// The text of the modifiers is empty if there are none.
+pub(crate) fn split_modifiers(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    let separator = key_modifier::SEPERATOR;
+
+    // The key code "+" itself would be lost by splitting at the separator.
+    if text == separator {
+        ("", text)
+    } else if let Some(modifiers) = text.strip_suffix(&separator.repeat(2)) {
+        (modifiers, separator)
+    } else if let Some(split) = split_code_point(text) {
+        split
+    } else {
+        text.rsplit_once(separator).unwrap_or(("", text))
+    }
+}
+
+/// Parses a compact form whose key code is not the last part like "a+CTRL".
+///
+/// Only "+" separates the parts here. The key code "+" itself and code points
+/// must be the last part.
+fn key_event_from_any_order(
+    text: &str,
+    mode: ParseMode,
+    fallback: Option<KeywordFallback>,
+) -> Result<KeyEvent, KeyParseError> {
+    let text = text.trim();
+    let separator = key_modifier::SEPERATOR;
+    let mut code = None;
+    let mut modifiers = Vec::new();
+    let mut position = 0;
+    for token in text.split(separator) {
+        let trimmed = token.trim();
+        if trimmed.is_empty() {
+            // The separator after an empty token or before the empty last token.
+            let position = if position == 0 { 0 } else { position - 1 };
+            return Err(KeyParseError::DanglingSeparator(text.to_string(), position));
+        } else if key_modifier::is_keyword(trimmed) {
+            modifiers.push(trimmed);
+        } else if code.is_none() {
+            code = Some(trimmed);
+        } else {
+            return Err(KeyParseError::AmbiguousKeyCode(text.to_string()));
+        }
+        position += token.chars().count() + 1;
+    }
+
+    let code = code.ok_or_else(|| KeyParseError::MissingKeyCode(text.to_string()))?;
+    let code = key_code::parse_key_code_with_fallback(code, mode, fallback)?;
+    let modifiers = key_modifier::parse_key_modifier_with(&modifiers.join(separator), mode)?;
+    Ok(KeyEvent::new(code, modifiers))
+}
+
+/// The "+" of a code point like in "CTRL+U+001B" is not a separator.
+///
+/// Only a whole key part "U+" with at least 4 hex digits is a code point as it is written,
+/// so "CTRL+U+a" is not read as a code point.
+fn split_code_point(text: &str) -> Option<(&str, &str)> {
+    const MIN_DIGITS: usize = 4;
+    let separator = key_modifier::SEPERATOR;
+    let (rest, digits) = text.rsplit_once(separator)?;
+    let modifiers = rest.strip_suffix('U')?;
+    if digits.len() < MIN_DIGITS || !digits.chars().all(|next| next.is_ascii_hexdigit()) {
+        return None;
+    }
+    if modifiers.is_empty() {
+        Some(("", text))
+    } else {
+        let code = &text[modifiers.len()..];
+        modifiers
+            .strip_suffix(separator)
+            .map(|modifiers| (modifiers, code))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::{
+        KeyEventCodec, ModifierCase, ModifierNaming, ModifiersStyle, ShiftNormalization,
+        SidedKeyEvent,
+    };
+    use crossterm::event::{KeyCode, KeyEventKind, MediaKeyCode, ModifierKeyCode};
+
+    #[test]
+    fn should_parse_compact_form() {
+        assert_case("a", KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+        assert_case("Up", KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_case(
+            "CONTROL+ALT+a",
+            KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
+            ),
+        );
+        assert_case("+", KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE));
+        assert_case(
+            "CONTROL++",
+            KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL),
+        );
+        assert_case(
+            "CTRL+a",
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+        );
+        assert_case(
+            "U+0041",
+            KeyEvent::new(KeyCode::Char('A'), KeyModifiers::NONE),
+        );
+        assert_case(
+            "CTRL+ALT+U+001B",
+            KeyEvent::new(
+                KeyCode::Char('\u{1b}'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            ),
+        );
+        assert_case(
+            "CTRL+U",
+            KeyEvent::new(KeyCode::Char('U'), KeyModifiers::CONTROL),
+        );
+        fn assert_case(input: &str, expected: KeyEvent) {
+            let actual = key_event_from_str(input).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+    #[test]
+    fn should_read_code_point_only_with_at_least_4_digits() {
+        assert_eq!(
+            Err(KeyParseError::UnknownModifier("U".to_string())),
+            key_event_from_str("CTRL+U+a")
+        );
+        assert_eq!(
+            Err(KeyParseError::UnknownModifier("U".to_string())),
+            key_event_from_str("SHIFT+U+E")
+        );
+        assert_eq!(
+            Ok(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL)),
+            key_event_from_str("CTRL+U+0061")
+        );
+        assert!(key_event_from_str("U+1B").is_err());
+    }
+    #[test]
+    fn should_parse_key_code_in_any_order() {
+        let expected = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        assert_eq!(expected, key_event_from_str("a+CTRL").unwrap());
+        assert_eq!(expected, key_event_from_str("CTRL+a").unwrap());
+        assert_eq!(
+            KeyEvent::new(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::ALT),
+            key_event_from_str("ctrl + Up + alt").unwrap()
+        );
+    }
+    #[test]
+    fn should_deny_no_or_several_key_codes_in_any_order() {
+        assert_case(
+            "CTRL+SHIFT",
+            KeyParseError::MissingKeyCode("CTRL+SHIFT".to_string()),
+            "CTRL+SHIFT has no key code, only modifiers",
+        );
+        assert_case(
+            "a+b+CTRL",
+            KeyParseError::AmbiguousKeyCode("a+b+CTRL".to_string()),
+            "a+b+CTRL has more than one key code, a key event has only one key code",
+        );
+        assert_case(
+            "a++CTRL",
+            KeyParseError::DanglingSeparator("a++CTRL".to_string(), 1),
+            "a++CTRL has a + without a modifier keyword next to it at position 1",
+        );
+        fn assert_case(input: &str, expected: KeyParseError, message: &str) {
+            let actual = key_event_from_str(input).unwrap_err();
+            assert_eq!(expected, actual);
+            assert_eq!(message, actual.to_string());
+        }
+    }
+    #[test]
+    fn should_deny_invalid_compact_form() {
+        assert_case("");
+        assert_case("CONTROL+");
+        assert_case("AL+a");
+        assert_case("ALT");
+        fn assert_case(input: &str) {
+            assert!(key_event_from_str(input).is_err());
+        }
+    }
+    #[test]
+    fn should_round_trip_doc_example() {
+        assert_case(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE | KeyModifiers::ALT | KeyModifiers::CONTROL,
+        ));
+        assert_case(KeyEvent::new(KeyCode::Up, KeyModifiers::ALT));
+        fn assert_case(input: KeyEvent) {
+            let text = key_event_to_string(&input).unwrap();
+            let actual = key_event_from_str(&text).unwrap();
+            assert_eq!(input, actual);
+        }
+    }
+    #[test]
+    fn should_produce_compact_form() {
+        assert_case(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE), "Up");
+        assert_case(KeyEvent::new(KeyCode::Up, KeyModifiers::ALT), "ALT+Up");
+        assert_case(
+            KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
+            ),
+            "CONTROL+ALT+a",
+        );
+        assert_case(
+            KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL),
+            "CONTROL++",
+        );
+        fn assert_case(input: KeyEvent, expected: &str) {
+            let actual = key_event_to_string(&input).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+    #[test]
+    fn should_parse_modifier_keys_as_key_code() {
+        let left = key_event_from_str("SHIFT+LeftAlt").unwrap();
+        let right = key_event_from_str("SHIFT+RightAlt").unwrap();
+        assert_eq!(
+            KeyEvent::new(
+                KeyCode::Modifier(ModifierKeyCode::LeftAlt),
+                KeyModifiers::SHIFT
+            ),
+            left
+        );
+        assert_ne!(left, right);
+    }
+    #[test]
+    fn should_parse_leading_modifier_keys_to_distinct_events() {
+        let left: SidedKeyEvent = "LeftAlt+x".parse().unwrap();
+        let right: SidedKeyEvent = "RightAlt+x".parse().unwrap();
+        assert_eq!(key_event_from_str("ALT+x").unwrap(), left.event);
+        assert_eq!(left.event, right.event);
+        assert_ne!(left, right);
+    }
+    #[test]
+    fn should_produce_same_canonical_key_for_every_codec() {
+        let codecs = [
+            KeyEventCodec::default(),
+            KeyEventCodec::builder()
+                .modifier_case(ModifierCase::Lowercase)
+                .modifier_naming(ModifierNaming::MacStyle)
+                .build(),
+            KeyEventCodec::builder()
+                .modifiers_style(ModifiersStyle::Array)
+                .shift_normalization(ShiftNormalization::LowercaseWithShift)
+                .build(),
+        ];
+        let input = KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SUPER | KeyModifiers::ALT);
+        for codec in codecs {
+            let mut json = Vec::new();
+            codec
+                .serialize(&input, &mut serde_json::Serializer::new(&mut json))
+                .unwrap();
+            let back = codec
+                .deserialize(&mut serde_json::Deserializer::from_slice(&json))
+                .unwrap();
+            assert_eq!("SHIFT+ALT+SUPER+A", canonical_key_event_key(&back));
+        }
+    }
+    #[test]
+    fn should_produce_canonical_key_for_every_key_code() {
+        let mut repeat = KeyEvent::new(KeyCode::Up, KeyModifiers::CONTROL);
+        repeat.kind = KeyEventKind::Repeat;
+        assert_eq!("CONTROL+Up", canonical_key_event_key(&repeat));
+        let control_char = KeyEvent::new(KeyCode::Char('\u{1}'), KeyModifiers::NONE);
+        assert_eq!("U+0001", canonical_key_event_key(&control_char));
+        let escape_char = KeyEvent::new(KeyCode::Char('\u{1b}'), KeyModifiers::NONE);
+        assert_eq!("Esc", canonical_key_event_key(&escape_char));
+        let media = KeyEvent::new(KeyCode::Media(MediaKeyCode::Play), KeyModifiers::NONE);
+        assert_eq!("Media(Play)", canonical_key_event_key(&media));
+        let out_of_range = KeyEvent::new(KeyCode::F(30), KeyModifiers::NONE);
+        assert_eq!("F(30)", canonical_key_event_key(&out_of_range));
+    }
+}
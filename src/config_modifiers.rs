@@ -0,0 +1,114 @@
+use crossterm::event::KeyModifiers;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display};
+
+use crate::key_event_serde::serde_key_modifier;
+use crate::key_text::key_modifier;
+use crate::KeyParseError;
+
+/// Key modifiers without a key code like for a setting of a leader modifier.
+///
+/// The text is the same as for the modifiers of a key event: keywords joined by "+" like
+/// "CONTROL+ALT". On parsing the case and the order of the keywords do not matter.
+/// [`Display`] writes the keywords in the [`CANONICAL_MODIFIER_ORDER`](crate::CANONICAL_MODIFIER_ORDER)
+/// and "NONE" for no modifiers.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyModifiers;
+/// use crossterm_serde::ConfigModifiers;
+///
+/// let leader = ConfigModifiers::try_from("alt+control").unwrap();
+/// assert_eq!(ConfigModifiers(KeyModifiers::CONTROL | KeyModifiers::ALT), leader);
+/// assert_eq!("CONTROL+ALT", leader.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConfigModifiers(pub KeyModifiers);
+
+impl Default for ConfigModifiers {
+    fn default() -> Self {
+        Self(KeyModifiers::NONE)
+    }
+}
+
+impl From<KeyModifiers> for ConfigModifiers {
+    fn from(modifiers: KeyModifiers) -> Self {
+        Self(modifiers)
+    }
+}
+
+impl From<ConfigModifiers> for KeyModifiers {
+    fn from(modifiers: ConfigModifiers) -> Self {
+        modifiers.0
+    }
+}
+
+impl TryFrom<&str> for ConfigModifiers {
+    type Error = KeyParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        key_modifier::parse_key_modifier(text).map(Self)
+    }
+}
+
+impl Display for ConfigModifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keywords = key_modifier::bits_to_strs(&self.0);
+        write!(f, "{}", keywords.join(key_modifier::SEPERATOR))
+    }
+}
+
+impl Serialize for ConfigModifiers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serde_key_modifier::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigModifiers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_key_modifier::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_via_display_and_try_from() {
+        let parsed = ConfigModifiers::try_from("ALT+CONTROL").unwrap();
+        assert_eq!(
+            ConfigModifiers(KeyModifiers::ALT | KeyModifiers::CONTROL),
+            parsed
+        );
+        let text = parsed.to_string();
+        assert_eq!("CONTROL+ALT", text);
+        assert_eq!(parsed, ConfigModifiers::try_from(text.as_str()).unwrap());
+    }
+    #[test]
+    fn should_display_none_for_no_modifiers() {
+        assert_eq!("NONE", ConfigModifiers::default().to_string());
+        assert_eq!(
+            ConfigModifiers::default(),
+            ConfigModifiers::try_from("NONE").unwrap()
+        );
+    }
+    #[test]
+    fn should_deny_invalid_modifiers() {
+        assert_eq!(
+            Err(KeyParseError::EmptyModifiers),
+            ConfigModifiers::try_from(" ")
+        );
+        assert_eq!(
+            Err(KeyParseError::UnknownModifier("ALTT".to_string())),
+            ConfigModifiers::try_from("CONTROL+ALTT")
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+use crate::compact;
+use crate::KeyParseError;
+
+/// Key event which is to be repeated a number of times like "3j" in vim.
+///
+/// The text is a count followed by the compact form of a key event like "3j" or "2CONTROL+d".
+/// Without a count like "j" the count is 1. The count is only written out if it is not 1.
+///
+/// The count always belongs to exactly one key event. There are no chords of several key events
+/// for which one count applies to. Modifiers are part of that one key event and therefore come after
+/// the count: "3CONTROL+a" instead of "CONTROL+3a".
+///
+/// A text only made of digits like "5" or "35" is read as a digit key with an optional count.
+/// "35" is therefore 3 times the key 5.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::CountedKeyEvent;
+///
+/// let counted: CountedKeyEvent = serde_json::from_str(r#""3j""#).unwrap();
+/// assert_eq!(
+///     CountedKeyEvent {
+///         count: 3,
+///         event: KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)
+///     },
+///     counted
+/// );
+/// assert_eq!(r#""3j""#, serde_json::to_string(&counted).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CountedKeyEvent {
+    pub count: u32,
+    pub event: KeyEvent,
+}
+
+impl Default for CountedKeyEvent {
+    fn default() -> Self {
+        Self {
+            count: 1,
+            event: KeyEvent::new(KeyCode::Null, KeyModifiers::NONE),
+        }
+    }
+}
+
+impl CountedKeyEvent {
+    fn to_text(self) -> Result<String, KeyParseError> {
+        let event = compact::key_event_to_string(&self.event)?;
+        if self.count == 1 {
+            Ok(event)
+        } else {
+            Ok(format!("{}{}", self.count, event))
+        }
+    }
+}
+
+impl FromStr for CountedKeyEvent {
+    type Err = KeyParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let digits_end = text
+            .find(|next: char| !next.is_ascii_digit())
+            .unwrap_or(text.len());
+        // The last digit is the key code if there is nothing after the digits.
+        let digits_end = if digits_end == text.len() {
+            digits_end.saturating_sub(1)
+        } else {
+            digits_end
+        };
+
+        let (count, event) = text.split_at(digits_end);
+        let count = if count.is_empty() {
+            1
+        } else {
+            count
+                .parse()
+                .ok()
+                .filter(|&count| count > 0)
+                .ok_or_else(|| KeyParseError::InvalidRepeatCount(count.to_string()))?
+        };
+        let event = compact::key_event_from_str(event)?;
+        Ok(Self { count, event })
+    }
+}
+
+impl Serialize for CountedKeyEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let text = self.to_text().map_err(ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for CountedKeyEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_parse_count_before_key_event() {
+        assert_case(
+            "3j",
+            3,
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+        );
+        assert_case(
+            "12CONTROL+d",
+            12,
+            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        );
+        assert_case("2Up", 2, KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_case(
+            "35",
+            3,
+            KeyEvent::new(KeyCode::Char('5'), KeyModifiers::NONE),
+        );
+        fn assert_case(input: &str, count: u32, event: KeyEvent) {
+            let actual: CountedKeyEvent = input.parse().unwrap();
+            assert_eq!(CountedKeyEvent { count, event }, actual);
+        }
+    }
+    #[test]
+    fn should_default_to_count_of_one() {
+        assert_case("j", KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert_case("5", KeyEvent::new(KeyCode::Char('5'), KeyModifiers::NONE));
+        assert_case("ALT+Up", KeyEvent::new(KeyCode::Up, KeyModifiers::ALT));
+        fn assert_case(input: &str, event: KeyEvent) {
+            let actual: CountedKeyEvent = input.parse().unwrap();
+            assert_eq!(CountedKeyEvent { count: 1, event }, actual);
+        }
+    }
+    #[test]
+    fn should_deny_zero_count() {
+        let actual = "0j".parse::<CountedKeyEvent>();
+        assert_eq!(
+            Err(KeyParseError::InvalidRepeatCount("0".to_string())),
+            actual
+        );
+    }
+    #[test]
+    fn should_round_trip_via_serde() {
+        assert_case(r#""3j""#);
+        assert_case(r#""j""#);
+        assert_case(r#""2CONTROL+ALT+a""#);
+        fn assert_case(input: &str) {
+            let counted: CountedKeyEvent = serde_json::from_str(input).unwrap();
+            let actual = serde_json::to_string(&counted).unwrap();
+            assert_eq!(input, actual);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::de::DeserializeOwned;
+
+use crate::KeyParseError;
+
+/// Reads the built-in default keymap of an app from RON like one embedded via `include_str!`.
+///
+/// The error tells the line and column within the RON and the reason like an invalid key event,
+/// so a broken default keymap is easy to find during development.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyEvent;
+/// use crossterm_serde::{key_event_from_str, load_default_keymap, serde_keymap};
+/// use serde::Deserialize;
+/// use std::collections::HashMap;
+///
+/// #[derive(Deserialize)]
+/// struct Keymap {
+///     #[serde(with = "serde_keymap")]
+///     bindings: HashMap<String, KeyEvent>,
+/// }
+///
+/// const DEFAULT_KEYMAP: &str = r#"(bindings: { "save": "CONTROL+s", "quit": "q" })"#;
+/// let keymap: Keymap = load_default_keymap(DEFAULT_KEYMAP).unwrap();
+/// assert_eq!(key_event_from_str("CONTROL+s").unwrap(), keymap.bindings["save"]);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the text is not valid RON or does not fit the type.
+pub fn load_default_keymap<T: DeserializeOwned>(ron: &str) -> Result<T, KeyParseError> {
+    ron::from_str(ron).map_err(|error| {
+        KeyParseError::InvalidDefaultKeymap(format!(
+            "line {}, column {}: {}",
+            error.position.line, error.position.col, error.code
+        ))
+    })
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::KeyEventDef;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use serde::Deserialize;
+
+    const DEFAULT_KEYMAP: &str = r#"(
+    up: (code: "Up"),
+    save: "CONTROL+s",
+)"#;
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Keymap {
+        up: KeyEventDef,
+        save: KeyEventDef,
+    }
+
+    #[test]
+    fn should_load_default_keymap() {
+        let actual: Keymap = load_default_keymap(DEFAULT_KEYMAP).unwrap();
+        assert_eq!(
+            Keymap {
+                up: KeyEventDef(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+                save: KeyEventDef(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            },
+            actual
+        );
+    }
+    #[test]
+    fn should_tell_position_of_invalid_key_event() {
+        let actual =
+            load_default_keymap::<Keymap>("(\n    up: (code: \"Upp\"),\n    save: \"s\",\n)")
+                .unwrap_err();
+        assert_eq!(
+            "Default keymap is not valid at line 2, column 21: 'Upp' is not a recognized key name, did you mean 'Up'?",
+            actual.to_string()
+        );
+    }
+}
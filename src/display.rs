@@ -0,0 +1,233 @@
+#[cfg(feature = "modifier_symbols")]
+use crossterm::event::KeyModifiers;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::key_text::key_code;
+use crate::{canonical_key_event_key, key_event_to_string};
+
+/// How [`display_name`] writes a key code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayStyle {
+    /// Keyword like "Up" or "Enter", the same as in a config.
+    #[default]
+    Words,
+    /// Glyph like "↑" or "⏎" for keys which have a common one, a keyword for any other key.
+    Symbols,
+}
+
+/// Readable name of a key code for a UI like a cheat sheet of the bindings.
+///
+/// Unlike serialize it never fails and the name does not need to be parsed back.
+/// A key code without a keyword like a media key is named by its variant like "Play"
+/// and a control char by its code point like "U+001B".
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyCode;
+/// use crossterm_serde::{display_name, DisplayStyle};
+///
+/// assert_eq!("Up", display_name(&KeyCode::Up, DisplayStyle::Words));
+/// assert_eq!("↑", display_name(&KeyCode::Up, DisplayStyle::Symbols));
+/// assert_eq!("a", display_name(&KeyCode::Char('a'), DisplayStyle::Symbols));
+/// ```
+pub fn display_name(code: &KeyCode, style: DisplayStyle) -> Cow<'static, str> {
+    if style == DisplayStyle::Symbols {
+        if let Some(symbol) = symbol(code) {
+            return Cow::Borrowed(symbol);
+        }
+    }
+    key_code::key_code_to_text(code).unwrap_or_else(|_| match code {
+        KeyCode::Media(media) => Cow::Owned(format!("{:?}", media)),
+        KeyCode::Char(control) => Cow::Owned(format!("U+{:04X}", *control as u32)),
+        other => Cow::Owned(format!("{:?}", other)),
+    })
+}
+
+/// Text table of the actions and their key events in the compact form like for `--list-keys`.
+///
+/// Every line has the action padded to the longest action, two spaces and the key event.
+/// The lines are sorted by action, so the output is stable.
+/// A key event without compact form like a media key is written like
+/// [`canonical_key_event_key`] instead.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::format_bindings_table;
+/// use std::collections::HashMap;
+///
+/// let map = HashMap::from([
+///     ("save", KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+///     ("up", KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+/// ]);
+/// assert_eq!("save  CONTROL+s\nup    Up\n", format_bindings_table(&map));
+/// ```
+pub fn format_bindings_table<A: Display>(map: &HashMap<A, KeyEvent>) -> String {
+    let mut rows: Vec<(String, String)> = map
+        .iter()
+        .map(|(action, event)| {
+            let key = key_event_to_string(event).unwrap_or_else(|_| canonical_key_event_key(event));
+            (action.to_string(), key)
+        })
+        .collect();
+    rows.sort();
+    let width = rows
+        .iter()
+        .map(|(action, _)| action.chars().count())
+        .max()
+        .unwrap_or(0);
+    rows.iter()
+        .map(|(action, key)| format!("{:<width$}  {}\n", action, key, width = width))
+        .collect()
+}
+
+/// Glyphs of the modifiers in the [`CANONICAL_MODIFIER_ORDER`](crate::CANONICAL_MODIFIER_ORDER)
+/// like "⇧⌃" for SHIFT and CONTROL, with the feature "modifier_symbols".
+///
+/// The glyphs are the ones of macOS: "⇧" for SHIFT, "⌃" for CONTROL, "⌥" for ALT
+/// and "⌘" for SUPER. HYPER is "✦" and META is "◆". No modifiers are an empty text.
+/// The text is only for display, the parser does not accept it.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyModifiers;
+/// use crossterm_serde::modifier_symbols;
+///
+/// assert_eq!("⌃⌘", modifier_symbols(&(KeyModifiers::SUPER | KeyModifiers::CONTROL)));
+/// ```
+#[cfg(feature = "modifier_symbols")]
+pub fn modifier_symbols(modifiers: &KeyModifiers) -> String {
+    crate::CANONICAL_MODIFIER_ORDER
+        .iter()
+        .filter(|(_, modifier)| modifiers.contains(*modifier))
+        .filter_map(|(_, modifier)| match *modifier {
+            KeyModifiers::SHIFT => Some("⇧"),
+            KeyModifiers::CONTROL => Some("⌃"),
+            KeyModifiers::ALT => Some("⌥"),
+            KeyModifiers::SUPER => Some("⌘"),
+            KeyModifiers::HYPER => Some("✦"),
+            KeyModifiers::META => Some("◆"),
+            _ => None,
+        })
+        .collect()
+}
+
+fn symbol(code: &KeyCode) -> Option<&'static str> {
+    let symbol = match code {
+        KeyCode::Up => "↑",
+        KeyCode::Down => "↓",
+        KeyCode::Left => "←",
+        KeyCode::Right => "→",
+        KeyCode::Enter => "⏎",
+        KeyCode::Esc => "⎋",
+        KeyCode::Tab => "⇥",
+        KeyCode::BackTab => "⇤",
+        KeyCode::Backspace => "⌫",
+        KeyCode::Delete => "⌦",
+        KeyCode::Home => "⇱",
+        KeyCode::End => "⇲",
+        KeyCode::PageUp => "⇞",
+        KeyCode::PageDown => "⇟",
+        KeyCode::Char(' ') => "␣",
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MediaKeyCode};
+
+    #[cfg(feature = "modifier_symbols")]
+    #[test]
+    fn should_write_modifier_symbols_in_canonical_order() {
+        assert_eq!(
+            "⇧⌃⌥⌘",
+            modifier_symbols(
+                &(KeyModifiers::SUPER
+                    | KeyModifiers::ALT
+                    | KeyModifiers::SHIFT
+                    | KeyModifiers::CONTROL)
+            )
+        );
+        assert_eq!(
+            "⌃✦◆",
+            modifier_symbols(
+                &KeyModifiers::all()
+                    .difference(KeyModifiers::SHIFT | KeyModifiers::ALT | KeyModifiers::SUPER)
+            )
+        );
+        assert_eq!("", modifier_symbols(&KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn should_name_arrows() {
+        assert_case(KeyCode::Up, "Up", "↑");
+        assert_case(KeyCode::Down, "Down", "↓");
+        assert_case(KeyCode::Left, "Left", "←");
+        assert_case(KeyCode::Right, "Right", "→");
+        fn assert_case(code: KeyCode, words: &str, symbols: &str) {
+            assert_eq!(words, display_name(&code, DisplayStyle::Words));
+            assert_eq!(symbols, display_name(&code, DisplayStyle::Symbols));
+        }
+    }
+    #[test]
+    fn should_fall_back_to_words_without_symbol() {
+        assert_eq!("F5", display_name(&KeyCode::F(5), DisplayStyle::Symbols));
+        assert_eq!(
+            "Space",
+            display_name(&KeyCode::Char(' '), DisplayStyle::Words)
+        );
+        assert_eq!(
+            "7",
+            display_name(&KeyCode::Char('7'), DisplayStyle::Symbols)
+        );
+    }
+    #[test]
+    fn should_align_and_sort_bindings_table() {
+        let map = HashMap::from([
+            (
+                "quit".to_string(),
+                KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE),
+            ),
+            (
+                "move_up".to_string(),
+                KeyEvent::new(KeyCode::Up, KeyModifiers::ALT),
+            ),
+            (
+                "play".to_string(),
+                KeyEvent::new(KeyCode::Media(MediaKeyCode::Play), KeyModifiers::NONE),
+            ),
+            (
+                "a".to_string(),
+                KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            ),
+        ]);
+        let expected = "\
+a        CONTROL+s
+move_up  ALT+Up
+play     Media(Play)
+quit     q
+";
+        assert_eq!(expected, format_bindings_table(&map));
+        assert_eq!("", format_bindings_table::<String>(&HashMap::new()));
+    }
+    #[test]
+    fn should_name_key_codes_without_text() {
+        assert_eq!(
+            "Play",
+            display_name(&KeyCode::Media(MediaKeyCode::Play), DisplayStyle::Words)
+        );
+        assert_eq!(
+            "U+001B",
+            display_name(&KeyCode::Char('\u{1b}'), DisplayStyle::Words)
+        );
+    }
+}
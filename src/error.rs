@@ -0,0 +1,217 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{self, Display};
+use crossterm::event::{KeyCode, KeyEventKind};
+
+use crate::key_text::key_code::{closest_keyword, control_char_keyword};
+
+/// Reason why a key code or key modifier could not be converted from or into its readable text.
+///
+/// The serde implementations of this crate turn this error into the error of the used format
+/// via `Error::custom`. The text of the error is the same as the [`Display`] output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyParseError {
+    /// Text for a key code was empty or only whitespace.
+    EmptyInput,
+    /// Text for a key code is neither one char nor a known keyword.
+    /// The message suggests the keyword which is spelled most like the text.
+    UnknownKeyword(String),
+    /// Text for a key code is a modifier keyword like ALT.
+    ModifierAsKeyCode(String),
+    /// Key code has no readable text representation.
+    UnsupportedKeyCode(KeyCode),
+    /// Text for a key code like "U+D800" has the shape of a code point but is not a valid char.
+    InvalidCodePoint(String),
+    /// Raw key code like `{ "raw": 55296 }` which is not a valid unicode scalar value.
+    InvalidRawCode(u32),
+    /// Text for a key code in strict mode is in quotes like "'a'".
+    QuotedKeyCode(String),
+    /// Text for a key code in strict mode is a single control char like a literal tab,
+    /// which is invisible in a config. Lenient mode reads it as that char.
+    InvalidChar(char),
+    /// Text for a key code is one visible char made of several chars like an emoji with a
+    /// skin tone. A key code of crossterm holds only a single char.
+    GraphemeCluster(String),
+    /// Function key outside of F1 to F24.
+    OutOfRangeFunctionKey(u8),
+    /// Text for the key modifiers was empty or only whitespace.
+    EmptyModifiers,
+    /// Text for the key modifiers contains a modifier key like LeftAlt, which is a key code.
+    /// Only a SidedKeyEvent reads one in front like "LeftAlt+x".
+    ModifierKeyAsModifier(String),
+    /// Text for the key modifiers contains a token which is not a valid keyword.
+    UnknownModifier(String),
+    /// Modifier bits as hex like "0x02" which have a keyword, given with the keywords to write instead.
+    HexForKnownModifier(String, String),
+    /// Modifier bits as hex like "0x40" in strict mode, which accepts only keywords.
+    HexModifier(String),
+    /// Modifier bits as hex like "0x40" which have no keyword, while the feature
+    /// "crossterm-0_27" is off. Only the bitflags of crossterm 0.27 and newer can hold them.
+    UnknownModifierBits(String),
+    /// Text for a key event kind is not Press, Repeat or Release.
+    UnknownEventKind(String),
+    /// Key event kind which is not in the allowed kinds of a `KeyEventCodec`.
+    DisallowedEventKind(KeyEventKind),
+    /// Text for the key modifiers has a separator without a keyword next to it like "CONTROL+".
+    /// The position is the index of the char of this separator.
+    DanglingSeparator(String, usize),
+    /// Text for the key modifiers in strict mode has NONE next to other keywords like "NONE+ALT".
+    NoneWithOtherModifiers(String),
+    /// Compact form made only of modifier keywords like "CTRL+SHIFT".
+    MissingKeyCode(String),
+    /// Compact form with more than one part which is not a modifier keyword like "a+b+CTRL".
+    AmbiguousKeyCode(String),
+    /// Key event in the serde representation of crossterm is not valid like in
+    /// `from_native_json` with the feature "native" and its reason.
+    InvalidNative(String),
+    /// Default keymap in RON given to `load_default_keymap` with the feature "ron" is not valid.
+    /// The reason starts with the line and column like "line 2, column 5: ...".
+    InvalidDefaultKeymap(String),
+    /// Count before a key event is zero or too big.
+    InvalidRepeatCount(String),
+    /// Modifiers which are required and negated at the same time.
+    RequiredAndNegated(String),
+    /// Token after a key event which does not start with "!" to negate a modifier.
+    MissingNegation(String),
+}
+
+impl Display for KeyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyParseError::EmptyInput => {
+                write!(f, "One char or a certain keyword must be provided")
+            }
+            KeyParseError::UnknownKeyword(token) => {
+                write!(f, "'{}' is not a recognized key name", token)?;
+                match closest_keyword(token) {
+                    Some(keyword) => write!(f, ", did you mean '{}'?", keyword),
+                    None => Ok(()),
+                }
+            }
+            KeyParseError::ModifierAsKeyCode(modifier) => write!(
+                f,
+                "'{}' is a modifier, not a key code; put it in the modifiers field",
+                modifier
+            ),
+            KeyParseError::UnsupportedKeyCode(_) => write!(
+                f,
+                "One char must be provided or a valie keyword for a key like (Up)"
+            ),
+            KeyParseError::InvalidCodePoint(code_point) => write!(
+                f,
+                "{} is not a valid char, a code point needs 1 up to 6 hex digits like U+001B",
+                code_point
+            ),
+            KeyParseError::InvalidRawCode(scalar) => write!(
+                f,
+                "{} is not a valid unicode scalar value for a raw key code",
+                scalar
+            ),
+            KeyParseError::InvalidChar(control) => {
+                let instead = match control_char_keyword(*control) {
+                    Some(keyword) => Cow::Borrowed(keyword),
+                    None => Cow::Owned(format!("U+{:04X}", u32::from(*control))),
+                };
+                write!(
+                    f,
+                    "{:?} is an invisible control char, write {} instead",
+                    control, instead
+                )
+            }
+            KeyParseError::QuotedKeyCode(text) => write!(
+                f,
+                "{} has extra quotes around the key code, write it without them",
+                text
+            ),
+            KeyParseError::GraphemeCluster(text) => write!(
+                f,
+                "key codes must be a single Unicode scalar value; '{}' is a multi-codepoint cluster",
+                text
+            ),
+            KeyParseError::OutOfRangeFunctionKey(number) => write!(
+                f,
+                "F{} is not a valid function key, only F1 up to F24 are supported",
+                number
+            ),
+            KeyParseError::EmptyModifiers => {
+                write!(f, "Need to provide at least keyword for the key modifier")
+            }
+            KeyParseError::ModifierKeyAsModifier(key) => write!(
+                f,
+                "'{}' is a key code, not a modifier; a key event has only one key code, a SidedKeyEvent reads a held key like 'LeftAlt+x'",
+                key
+            ),
+            KeyParseError::UnknownModifier(keyword) => {
+                write!(f, "{} is not a valid keyword", keyword)
+            }
+            KeyParseError::HexForKnownModifier(token, keywords) => write!(
+                f,
+                "{} sets modifiers which have a keyword, write {} instead",
+                token, keywords
+            ),
+            KeyParseError::HexModifier(token) => write!(
+                f,
+                "{} is a modifier bit as hex, which is only accepted in lenient mode",
+                token
+            ),
+            KeyParseError::UnknownModifierBits(token) => write!(
+                f,
+                "{} are modifier bits without a keyword, which are only kept with the feature crossterm-0_27",
+                token
+            ),
+            KeyParseError::UnknownEventKind(kind) => write!(
+                f,
+                "{} is not a valid key event kind, only Press, Repeat and Release are supported",
+                kind
+            ),
+            KeyParseError::DisallowedEventKind(kind) => write!(
+                f,
+                "{:?} events are not supported by this application",
+                kind
+            ),
+            KeyParseError::DanglingSeparator(modifiers, position) => write!(
+                f,
+                "{} has a + without a modifier keyword next to it at position {}",
+                modifiers, position
+            ),
+            KeyParseError::NoneWithOtherModifiers(_) => {
+                write!(f, "NONE cannot be combined with other modifiers")
+            }
+            KeyParseError::MissingKeyCode(text) => {
+                write!(f, "{} has no key code, only modifiers", text)
+            }
+            KeyParseError::AmbiguousKeyCode(text) => write!(
+                f,
+                "{} has more than one key code, a key event has only one key code",
+                text
+            ),
+            KeyParseError::InvalidNative(reason) => {
+                write!(f, "Not a key event of crossterm: {}", reason)
+            }
+            KeyParseError::InvalidDefaultKeymap(reason) => {
+                write!(f, "Default keymap is not valid at {}", reason)
+            }
+            KeyParseError::InvalidRepeatCount(count) => write!(
+                f,
+                "{} is not a valid repeat count, it must be between 1 and {}",
+                count,
+                u32::MAX
+            ),
+            KeyParseError::RequiredAndNegated(modifiers) => write!(
+                f,
+                "{} can not be required and negated at the same time",
+                modifiers
+            ),
+            KeyParseError::MissingNegation(token) => write!(
+                f,
+                "{} must start with ! to negate a modifier like !SHIFT",
+                token
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyParseError {}
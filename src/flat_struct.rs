@@ -0,0 +1,377 @@
+use serde::de::value::MapDeserializer;
+use serde::de::DeserializeOwned;
+use serde::ser::{self, Impossible, Serialize, SerializeStruct, Serializer};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+/// Reason why a struct could not be flattened by [`struct_to_flat`]
+/// or read back by [`struct_from_flat`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatError(String);
+
+impl Display for FlatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for FlatError {}
+
+impl ser::Error for FlatError {
+    fn custom<T: Display>(message: T) -> Self {
+        Self(message.to_string())
+    }
+}
+
+impl serde::de::Error for FlatError {
+    fn custom<T: Display>(message: T) -> Self {
+        Self(message.to_string())
+    }
+}
+
+/// Flattens a struct of key events into flat keys with the compact form like "CTRL+a" as values.
+///
+/// Every field must be written as text like by
+/// [`serde_key_event_compact`](crate::serde_key_event_compact). The flat key is the prefix
+/// followed by the name of the field like "KEYS_move_up" for the prefix "KEYS_",
+/// the same as [`keymap_to_flat`](crate::keymap_to_flat) does for a keymap.
+///
+/// # Errors
+///
+/// Returns an error if the value is no struct or a field is not written as text.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::{struct_from_flat, struct_to_flat};
+/// use serde::{Deserialize, Serialize};
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+/// pub struct KeyBoard {
+///     #[serde(with = "crossterm_serde::serde_key_event_compact")]
+///     move_up: KeyEvent,
+///     #[serde(with = "crossterm_serde::serde_key_event_compact")]
+///     save: KeyEvent,
+/// }
+///
+/// let key_board = KeyBoard {
+///     move_up: KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+///     save: KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+/// };
+/// let flat = struct_to_flat("KEYS_", &key_board).unwrap();
+/// assert_eq!(
+///     HashMap::from([
+///         ("KEYS_move_up".to_string(), "Up".to_string()),
+///         ("KEYS_save".to_string(), "CONTROL+s".to_string()),
+///     ]),
+///     flat
+/// );
+/// assert_eq!(key_board, struct_from_flat("KEYS_", &flat).unwrap());
+/// ```
+pub fn struct_to_flat<T: Serialize>(
+    prefix: &str,
+    value: &T,
+) -> Result<HashMap<String, String>, FlatError> {
+    value.serialize(FlatSerializer { prefix })
+}
+
+/// Reads a struct back from flat keys which start with the prefix like by [`struct_to_flat`].
+///
+/// The name of a field is the flat key without the prefix. Flat keys without the prefix
+/// are skipped, so all environment variables can be passed in.
+///
+/// # Errors
+///
+/// Returns an error like the deserialize of the struct if a field is missing or invalid.
+pub fn struct_from_flat<T: DeserializeOwned>(
+    prefix: &str,
+    flat: &HashMap<String, String>,
+) -> Result<T, FlatError> {
+    let fields = flat.iter().filter_map(|(key, text)| {
+        key.strip_prefix(prefix)
+            .map(|field| (field.to_string(), text.clone()))
+    });
+    T::deserialize(MapDeserializer::<_, FlatError>::new(fields))
+}
+
+fn unsupported<T>(what: &str) -> Result<T, FlatError> {
+    Err(FlatError(format!(
+        "{} can not be flattened, only a struct of key events as text",
+        what
+    )))
+}
+
+/// Methods of a serializer for every value besides text, newtypes and structs.
+macro_rules! unsupported_values {
+    () => {
+        fn serialize_bool(self, _: bool) -> Result<Self::Ok, Self::Error> {
+            unsupported("A bool")
+        }
+        fn serialize_i8(self, _: i8) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_i16(self, _: i16) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_i32(self, _: i32) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_i64(self, _: i64) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_u8(self, _: u8) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_u16(self, _: u16) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_u32(self, _: u32) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_u64(self, _: u64) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_f32(self, _: f32) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_f64(self, _: f64) -> Result<Self::Ok, Self::Error> {
+            unsupported("A number")
+        }
+        fn serialize_char(self, _: char) -> Result<Self::Ok, Self::Error> {
+            unsupported("A char")
+        }
+        fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
+            unsupported("Bytes")
+        }
+        fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+            unsupported("An option")
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+            unsupported("An option")
+        }
+        fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+            unsupported("A unit")
+        }
+        fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+            unsupported("A unit struct")
+        }
+        fn serialize_unit_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+        ) -> Result<Self::Ok, Self::Error> {
+            unsupported("An enum")
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            unsupported("An enum")
+        }
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            unsupported("A sequence")
+        }
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            unsupported("A tuple")
+        }
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            unsupported("A tuple")
+        }
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            unsupported("An enum")
+        }
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            unsupported("A map")
+        }
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            unsupported("An enum")
+        }
+    };
+}
+
+/// Accepts only a struct and writes its fields into the flat map.
+struct FlatSerializer<'a> {
+    prefix: &'a str,
+}
+
+struct FlatStruct<'a> {
+    prefix: &'a str,
+    flat: HashMap<String, String>,
+}
+
+impl SerializeStruct for FlatStruct<'_> {
+    type Ok = HashMap<String, String>;
+    type Error = FlatError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let text = value
+            .serialize(TextSerializer)
+            .map_err(|FlatError(reason)| {
+                FlatError(format!("{}{}: {}", self.prefix, key, reason))
+            })?;
+        self.flat.insert(format!("{}{}", self.prefix, key), text);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.flat)
+    }
+}
+
+impl<'a> Serializer for FlatSerializer<'a> {
+    type Ok = HashMap<String, String>;
+    type Error = FlatError;
+    type SerializeSeq = Impossible<Self::Ok, FlatError>;
+    type SerializeTuple = Impossible<Self::Ok, FlatError>;
+    type SerializeTupleStruct = Impossible<Self::Ok, FlatError>;
+    type SerializeTupleVariant = Impossible<Self::Ok, FlatError>;
+    type SerializeMap = Impossible<Self::Ok, FlatError>;
+    type SerializeStruct = FlatStruct<'a>;
+    type SerializeStructVariant = Impossible<Self::Ok, FlatError>;
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(FlatStruct {
+            prefix: self.prefix,
+            flat: HashMap::with_capacity(len),
+        })
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_str(self, _: &str) -> Result<Self::Ok, Self::Error> {
+        unsupported("A text")
+    }
+
+    unsupported_values!();
+}
+
+/// Accepts only a text like the compact form of a key event.
+struct TextSerializer;
+
+impl Serializer for TextSerializer {
+    type Ok = String;
+    type Error = FlatError;
+    type SerializeSeq = Impossible<String, FlatError>;
+    type SerializeTuple = Impossible<String, FlatError>;
+    type SerializeTupleStruct = Impossible<String, FlatError>;
+    type SerializeTupleVariant = Impossible<String, FlatError>;
+    type SerializeMap = Impossible<String, FlatError>;
+    type SerializeStruct = Impossible<String, FlatError>;
+    type SerializeStructVariant = Impossible<String, FlatError>;
+
+    fn serialize_str(self, text: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(text.to_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        unsupported("An object like { code: \"a\" }")
+    }
+
+    unsupported_values!();
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    struct KeyBoard {
+        #[serde(with = "crate::serde_key_event_compact")]
+        move_up: KeyEvent,
+        #[serde(with = "crate::serde_key_event_compact")]
+        quit: KeyEvent,
+    }
+
+    #[test]
+    fn should_round_trip_struct_via_flat_keys() {
+        let key_board = KeyBoard {
+            move_up: KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            quit: KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+        };
+        let flat = struct_to_flat("keys.", &key_board).unwrap();
+        assert_eq!(
+            HashMap::from([
+                ("keys.move_up".to_string(), "CONTROL+a".to_string()),
+                ("keys.quit".to_string(), "Esc".to_string()),
+            ]),
+            flat
+        );
+        let mut env = flat.clone();
+        env.insert("HOME".to_string(), "/home/user".to_string());
+        assert_eq!(key_board, struct_from_flat("keys.", &env).unwrap());
+    }
+    #[test]
+    fn should_report_invalid_flat_field() {
+        let flat = HashMap::from([
+            ("keys.move_up".to_string(), "CTRL+a".to_string()),
+            ("keys.quit".to_string(), "CTRL+Banana".to_string()),
+        ]);
+        let actual = struct_from_flat::<KeyBoard>("keys.", &flat).unwrap_err();
+        assert!(actual.to_string().contains("Banana"), "{}", actual);
+        let missing = HashMap::from([("keys.move_up".to_string(), "k".to_string())]);
+        let actual = struct_from_flat::<KeyBoard>("keys.", &missing).unwrap_err();
+        assert_eq!("missing field `quit`", actual.to_string());
+    }
+    #[test]
+    fn should_deny_field_which_is_no_text() {
+        #[derive(Serialize)]
+        struct Nested {
+            count: u32,
+        }
+        let actual = struct_to_flat("", &Nested { count: 2 }).unwrap_err();
+        assert_eq!(
+            "count: A number can not be flattened, only a struct of key events as text",
+            actual.to_string()
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use crate::key_text::{key_code, key_modifier};
+
+/// Schema of the readable key event of [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent)
+/// with the feature "schemars", which is the same as [`key_event_json_schema`].
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyEvent;
+/// use schemars::JsonSchema;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, JsonSchema)]
+/// pub struct Config {
+///     #[serde(with = "crossterm_serde::SerDeConfigKeyEvent")]
+///     #[schemars(with = "crossterm_serde::KeyEventSchema")]
+///     quit: KeyEvent,
+/// }
+///
+/// let schema = serde_json::to_value(schemars::schema_for!(Config)).unwrap();
+/// assert_eq!(
+///     "#/$defs/KeyEvent",
+///     schema["properties"]["quit"]["$ref"]
+/// );
+/// ```
+#[cfg(feature = "schemars")]
+#[derive(Debug, Clone, Copy)]
+pub struct KeyEventSchema;
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for KeyEventSchema {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "KeyEvent".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        concat!(module_path!(), "::KeyEvent").into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let schema: serde_json::Value =
+            serde_json::from_str(&key_event_json_schema()).expect("Schema is valid JSON");
+        schemars::Schema::try_from(schema).expect("Schema is a JSON object")
+    }
+}
+
+/// Returns a JSON schema for the readable key event of
+/// [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent).
+///
+/// It can be embedded into the schema of a configuration, so editors can validate and
+/// auto complete key bindings. The schema describes what the default deserialize accepts:
+///
+/// - `code`: a single char, a keyword like "Up", a code point like "U+001B"
+///   or a function key like "F5" or `{ "f": 5 }` or a char as number like `{ "raw": 57358 }`.
+/// - `modifiers`: keywords in any case joined by "+", "," or whitespace
+///   or a sequence of keywords. It may be omitted.
+///
+/// # Example
+///
+/// ```
+/// let schema: serde_json::Value =
+///     serde_json::from_str(&crossterm_serde::key_event_json_schema()).unwrap();
+/// assert_eq!("object", schema["type"]);
+/// ```
+pub fn key_event_json_schema() -> String {
+    let mut code_keywords: Vec<String> = key_code::keyword_names()
+        .map(ToString::to_string)
+        .chain(key_code::FUNCTION_KEYS.map(|number| format!("F{}", number)))
+        .collect();
+    code_keywords.sort();
+    let code_keywords = json_string_list(&code_keywords);
+
+    let mut modifier_keywords: Vec<&str> = key_modifier::keyword_names().collect();
+    modifier_keywords.sort();
+    let any_case_keyword = modifier_keywords
+        .iter()
+        .map(|keyword| any_case(keyword))
+        .collect::<Vec<String>>()
+        .join("|");
+    let delimiters: String = key_modifier::LENIENT_DELIMITERS.iter().collect();
+    let pattern = format!(
+        r"^\\s*({keyword})([{delimiters}\\s]+({keyword}))*\\s*$",
+        keyword = any_case_keyword,
+        delimiters = delimiters,
+    );
+    let modifier_keywords = json_string_list(&modifier_keywords);
+    let first_function_key = key_code::FUNCTION_KEYS.start();
+    let last_function_key = key_code::FUNCTION_KEYS.end();
+
+    format!(
+        r#"{{
+  "type": "object",
+  "properties": {{
+    "code": {{
+      "description": "Single char or keyword for a key like Up",
+      "anyOf": [
+        {{ "type": "string", "minLength": 1, "maxLength": 1 }},
+        {{ "type": "string", "enum": [{code_keywords}] }},
+        {{ "type": "string", "pattern": "^(U\\+[0-9A-Fa-f]{{1,6}}|\\\\u\\{{[0-9A-Fa-f]{{1,6}}\\}})$" }},
+        {{ "type": "integer", "minimum": 0, "maximum": 9 }},
+        {{
+          "type": "object",
+          "properties": {{
+            "f": {{ "type": "integer", "minimum": {first_function_key}, "maximum": {last_function_key} }}
+          }},
+          "required": ["f"],
+          "additionalProperties": false
+        }},
+        {{
+          "type": "object",
+          "properties": {{
+            "raw": {{ "type": "integer", "minimum": 0, "maximum": 1114111 }}
+          }},
+          "required": ["raw"],
+          "additionalProperties": false
+        }}
+      ]
+    }},
+    "modifiers": {{
+      "description": "Modifier keywords joined by + like CONTROL+ALT",
+      "anyOf": [
+        {{ "type": "string", "pattern": "{pattern}" }},
+        {{ "type": "array", "items": {{ "type": "string", "enum": [{modifier_keywords}] }} }},
+        {{
+          "type": "object",
+          "propertyNames": {{ "enum": [{modifier_keywords}] }},
+          "additionalProperties": {{ "type": "boolean" }}
+        }}
+      ]
+    }}
+  }},
+  "required": ["code"]
+}}"#
+    )
+}
+
+fn json_string_list(values: &[impl AsRef<str>]) -> String {
+    values
+        .iter()
+        .map(|value| format!("\"{}\"", value.as_ref()))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Regex for a keyword with any case like "[aA][lL][tT]" since JSON schema has no flag for it.
+fn any_case(keyword: &str) -> String {
+    keyword
+        .chars()
+        .map(|next| {
+            format!(
+                "[{}{}]",
+                next.to_ascii_lowercase(),
+                next.to_ascii_uppercase()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_contain_field_names() {
+        let schema: serde_json::Value = serde_json::from_str(&key_event_json_schema()).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("code"));
+        assert!(properties.contains_key("modifiers"));
+        assert_eq!(serde_json::json!(["code"]), schema["required"]);
+        assert_eq!(
+            "Single char or keyword for a key like Up",
+            properties["code"]["description"]
+        );
+    }
+    #[test]
+    fn should_list_keywords() {
+        let schema: serde_json::Value = serde_json::from_str(&key_event_json_schema()).unwrap();
+        let code_keywords = &schema["properties"]["code"]["anyOf"][1]["enum"];
+        for expected in ["Up", "Enter", "Space", "F1", "F24"] {
+            assert!(
+                code_keywords
+                    .as_array()
+                    .unwrap()
+                    .contains(&serde_json::json!(expected)),
+                "Missing {}",
+                expected
+            );
+        }
+        let pattern = schema["properties"]["modifiers"]["anyOf"][0]["pattern"]
+            .as_str()
+            .unwrap();
+        assert!(pattern.contains("[aA][lL][tT]"));
+        let code_point = schema["properties"]["code"]["anyOf"][2]["pattern"]
+            .as_str()
+            .unwrap();
+        assert_eq!(
+            r"^(U\+[0-9A-Fa-f]{1,6}|\\u\{[0-9A-Fa-f]{1,6}\})$",
+            code_point
+        );
+    }
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn should_contain_field_names_in_schemars_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(KeyEventSchema)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("code"));
+        assert!(properties.contains_key("modifiers"));
+        assert_eq!(serde_json::json!(["code"]), schema["required"]);
+        assert_eq!(
+            "Modifier keywords joined by + like CONTROL+ALT",
+            properties["modifiers"]["description"]
+        );
+    }
+}
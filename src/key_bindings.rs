@@ -0,0 +1,329 @@
+use crossterm::event::KeyEvent;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::key_event_serde::KeyEventDef;
+use crate::serde_keymap::MaybeBinding;
+use crate::{invert_keymap, BindingConflict, BindingKey};
+
+/// Keymap from the name of an action to its key event, which rejects two actions with the same key.
+/// It is the ready-made keymap type of a config, so there is no separate KeyMap type.
+/// It is the ready-made keymap type of a config, there is no separate KeyMap type.
+/// It is written as map from action to the readable key event of
+/// [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent).
+/// Key events are compared without kind and state, see [`BindingKey`].
+/// The key event of an action is kept as written, so serialize writes the config back unchanged.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+/// use crossterm_serde::KeyBindings;
+///
+/// let bindings: KeyBindings = serde_json::from_str(
+///     r#"{ "save": { "code": "s", "modifiers": "CONTROL" }, "up": { "code": "Up" } }"#,
+/// )
+/// .unwrap();
+/// let incoming = KeyEvent::new_with_kind(KeyCode::Up, KeyModifiers::NONE, KeyEventKind::Repeat);
+/// assert_eq!(Some("up"), bindings.action_for(&incoming));
+/// assert_eq!(
+///     Some(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+///     bindings.get("save")
+/// );
+///
+/// let with_collision = serde_json::from_str::<KeyBindings>(
+///     r#"{ "up": { "code": "k" }, "previous": { "code": "k" } }"#,
+/// );
+/// assert!(with_collision.is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyBindings {
+    /// Key event of every action as written.
+    events: HashMap<String, KeyEvent>,
+    /// Action of every key for looking up an incoming key event.
+    actions: HashMap<BindingKey, String>,
+}
+
+impl KeyBindings {
+    /// # Errors
+    ///
+    /// Returns all conflicts if at least 2 actions are bound to the same key.
+    pub fn new(map: &HashMap<String, KeyEvent>) -> Result<Self, Vec<BindingConflict>> {
+        invert_keymap(map).map(|actions| Self {
+            events: map.clone(),
+            actions,
+        })
+    }
+
+    /// Returns the key event bound to the action like for showing it in a help text.
+    pub fn get(&self, action: &str) -> Option<&KeyEvent> {
+        self.events.get(action)
+    }
+
+    /// Returns the action bound to the key event ignoring its kind and state.
+    pub fn action_for(&self, event: &KeyEvent) -> Option<&str> {
+        self.actions
+            .get(&BindingKey::from(event))
+            .map(String::as_str)
+    }
+
+    /// Deserializes like [`Deserialize`] but also rejects actions which are not in the actions
+    /// of the app, so a typo in an action name does not bind a key to nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns one error with every invalid key event, every unknown action and every conflict
+    /// separated by "; ".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crossterm_serde::KeyBindings;
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(r#"{ "sav": { "code": "s" } }"#);
+    /// let error = KeyBindings::deserialize_with_actions(&mut deserializer, &["save", "quit"]);
+    /// assert!(error.unwrap_err().to_string().contains("sav is not an action"));
+    /// ```
+    pub fn deserialize_with_actions<'de, D>(
+        deserializer: D,
+        actions: &[&str],
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(KeyBindingsVisitor {
+            actions: Some(actions),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Actions with their key event as written in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &KeyEvent)> {
+        self.events
+            .iter()
+            .map(|(action, event)| (action.as_str(), event))
+    }
+}
+
+impl Serialize for KeyBindings {
+    /// Actions are written in alphabetical order.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sorted: BTreeMap<&str, KeyEventDef> = self
+            .events
+            .iter()
+            .map(|(action, event)| (action.as_str(), KeyEventDef(*event)))
+            .collect();
+        sorted.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(KeyBindingsVisitor { actions: None })
+    }
+}
+
+struct KeyBindingsVisitor<'a> {
+    /// Allowed actions, any action is allowed if none.
+    actions: Option<&'a [&'a str]>,
+}
+
+impl<'de> Visitor<'de> for KeyBindingsVisitor<'_> {
+    type Value = KeyBindings;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map from action to key event")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut by_action = HashMap::with_capacity(map.size_hint().unwrap_or_default());
+        let mut errors = Vec::new();
+        while let Some((action, binding)) = map.next_entry::<String, MaybeBinding>()? {
+            if let Some(actions) = self.actions {
+                if !actions.contains(&action.as_str()) {
+                    errors.push(format!("{} is not an action of this application", action));
+                }
+            }
+            match binding.parse(&action) {
+                Ok(event) => {
+                    by_action.insert(action, event);
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+        errors.sort();
+
+        match KeyBindings::new(&by_action) {
+            Ok(bindings) if errors.is_empty() => Ok(bindings),
+            Ok(_) => Err(de::Error::custom(errors.join("; "))),
+            Err(conflicts) => {
+                errors.extend(conflicts.iter().map(ToString::to_string));
+                Err(de::Error::custom(errors.join("; ")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    const RON_INPUT: &str = r#"
+{
+    "move_up": (code: "k"),
+    "move_down": (code: "j"),
+    "save": (code: "s", modifiers: "CONTROL"),
+}
+    "#;
+
+    #[test]
+    fn should_deserialize_bindings() {
+        let actual: KeyBindings = ron::from_str(RON_INPUT).unwrap();
+        assert_eq!(3, actual.len());
+        assert_case(&actual, KeyCode::Char('k'), KeyModifiers::NONE, "move_up");
+        assert_case(&actual, KeyCode::Char('j'), KeyModifiers::NONE, "move_down");
+        assert_case(&actual, KeyCode::Char('s'), KeyModifiers::CONTROL, "save");
+        assert_eq!(
+            None,
+            actual.action_for(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE))
+        );
+        fn assert_case(
+            bindings: &KeyBindings,
+            code: KeyCode,
+            modifiers: KeyModifiers,
+            expected: &str,
+        ) {
+            let actual = bindings.action_for(&KeyEvent::new(code, modifiers));
+            assert_eq!(Some(expected), actual);
+        }
+    }
+    #[test]
+    fn should_deny_two_actions_with_same_key() {
+        let input = r#"
+{
+    "move_up": (code: "k"),
+    "scroll_up": (code: "k", modifiers: "NONE"),
+}
+        "#;
+        let actual = ron::from_str::<KeyBindings>(input).unwrap_err();
+        assert!(
+            actual
+                .to_string()
+                .contains("Actions move_up and scroll_up are bound to the same key"),
+            "{}",
+            actual
+        );
+    }
+    #[test]
+    fn should_look_up_key_event_of_action() {
+        let bindings: KeyBindings = ron::from_str(RON_INPUT).unwrap();
+        assert_eq!(
+            Some(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            bindings.get("save")
+        );
+        assert_eq!(None, bindings.get("quit"));
+    }
+    #[test]
+    fn should_report_every_invalid_key_event() {
+        let input = r#"{
+    "move_up": { "code": "k" },
+    "scroll_up": { "code": "k" },
+    "quit": { "code": "q", "modifiers": "AL" },
+    "save": { "code": "Entr" },
+    "help": 42
+}"#;
+        let actual = serde_json::from_str::<KeyBindings>(input)
+            .unwrap_err()
+            .to_string();
+        for expected in [
+            "help: expected a key event",
+            "quit.modifiers: AL is not a valid keyword",
+            "save.code: ",
+            "Actions move_up and scroll_up are bound to the same key",
+        ] {
+            assert!(actual.contains(expected), "{}", actual);
+        }
+    }
+    #[test]
+    fn should_accept_only_allowed_actions() {
+        const ACTIONS: &[&str] = &["move_up", "move_down", "save"];
+        let actual = KeyBindings::deserialize_with_actions(
+            &mut ron::Deserializer::from_str(RON_INPUT).unwrap(),
+            ACTIONS,
+        )
+        .unwrap();
+        assert_eq!(
+            Some("save"),
+            actual.action_for(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+        );
+
+        let input = r#"
+{
+    "move_up": (code: "k"),
+    "scroll_up": (code: "k"),
+    "sav": (code: "s", modifiers: "CONTROL"),
+}
+        "#;
+        let actual = KeyBindings::deserialize_with_actions(
+            &mut ron::Deserializer::from_str(input).unwrap(),
+            ACTIONS,
+        )
+        .unwrap_err()
+        .to_string();
+        for expected in [
+            "sav is not an action of this application",
+            "scroll_up is not an action of this application",
+            "Actions move_up and scroll_up are bound to the same key",
+        ] {
+            assert!(actual.contains(expected), "{}", actual);
+        }
+    }
+    #[test]
+    fn should_write_key_events_as_written() {
+        let text = r#"{"big":{"code":"A","modifiers":"NONE"},"low":{"code":"b","modifiers":"SHIFT"},"tab":{"code":"Tab","modifiers":"SHIFT"}}"#;
+        let bindings: KeyBindings = serde_json::from_str(text).unwrap();
+        assert_eq!(text, serde_json::to_string(&bindings).unwrap());
+        assert_eq!(
+            Some(&KeyEvent::new(KeyCode::Char('b'), KeyModifiers::SHIFT)),
+            bindings.get("low")
+        );
+        assert_eq!(
+            Some("tab"),
+            bindings.action_for(&KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT))
+        );
+        assert_eq!(
+            Some("big"),
+            bindings.action_for(&KeyEvent::new(KeyCode::Char('a'), KeyModifiers::SHIFT))
+        );
+    }
+    #[test]
+    fn should_round_trip() {
+        let bindings: KeyBindings = ron::from_str(RON_INPUT).unwrap();
+        let text = serde_json::to_string(&bindings).unwrap();
+        assert_eq!(
+            r#"{"move_down":{"code":"j","modifiers":"NONE"},"move_up":{"code":"k","modifiers":"NONE"},"save":{"code":"s","modifiers":"CONTROL"}}"#,
+            text
+        );
+        let actual: KeyBindings = serde_json::from_str(&text).unwrap();
+        assert_eq!(bindings, actual);
+    }
+}
@@ -1,125 +1,391 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
-use once_cell::sync::Lazy;
 use serde::Serialize;
 use serde::{de, ser, Deserialize, Deserializer, Serializer};
-use std::collections::HashMap;
 
+use crate::key_text::{key_modifier, trim_config_text};
+use crate::{BlankModifiers, KeyEventCodec, KeyParseError, ParseMode};
+
+/// Readable serialization of a crossterm key event like `{ "code": "a", "modifiers": "ALT" }`.
+///
+/// It is used via `#[serde(with = "SerDeConfigKeyEvent")]` and behaves like the default
+/// [`KeyEventCodec`](crate::KeyEventCodec). Besides the structured form,
+/// deserialize also accepts a key event in its compact form like "CTRL+a".
+/// Kind and state of the key event are not part of the text.
+#[derive(Debug)]
+pub struct SerDeConfigKeyEvent {
+    _private: (),
+}
+
+impl SerDeConfigKeyEvent {
+    pub fn serialize<S>(event: &KeyEvent, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        KeyEventCodec::default().serialize(event, serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyEvent, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        KeyEventCodec::default().deserialize(deserializer)
+    }
+}
+
+/// Readable serialization of a key event with its kind like
+/// `{ "code": "a", "modifiers": "ALT", "kind": "Release" }`.
+///
+/// It is used via `#[serde(with = "SerDeConfigKeyEventWithKind")]` for bindings which
+/// tell pressing and releasing a key apart. Of the state of the key event only KEYPAD is
+/// written as `"keypad": true` for bindings of keys on the keypad like its Enter.
+/// Without KEYPAD the field is left out, so such key events have only code, modifiers and kind.
+/// Other bits of the state like CAPS_LOCK are skipped.
+/// Modifiers and keypad may be omitted, the kind must be given.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+/// use crossterm_serde::SerDeConfigKeyEventWithKind;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+/// pub struct Binding {
+///     #[serde(with = "SerDeConfigKeyEventWithKind")]
+///     stop_boost: KeyEvent,
+/// }
+///
+/// let binding = Binding {
+///     stop_boost: KeyEvent::new_with_kind(
+///         KeyCode::Char(' '),
+///         KeyModifiers::NONE,
+///         KeyEventKind::Release,
+///     ),
+/// };
+/// let string = serde_json::to_string(&binding).unwrap();
+/// assert_eq!(
+///     r#"{"stop_boost":{"code":"Space","modifiers":"NONE","kind":"Release"}}"#,
+///     string
+/// );
+/// assert_eq!(binding, serde_json::from_str(&string).unwrap());
+/// ```
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(remote = "KeyEvent")]
-pub struct SerDeConfigKeyEvent {
+pub struct SerDeConfigKeyEventWithKind {
     #[serde(with = "serde_key_code")]
     code: KeyCode,
     #[serde(default = "default_modifiers")]
     #[serde(with = "serde_key_modifier")]
     modifiers: KeyModifiers,
-    #[serde(skip)]
-    #[serde(default = "default_event_kind")]
+    #[serde(with = "serde_key_event_kind")]
     kind: KeyEventKind,
-    #[serde(skip)]
-    #[serde(default = "default_event_state")]
+    #[serde(rename = "keypad", with = "serde_keypad_state")]
+    #[serde(default = "default_event_state", skip_serializing_if = "is_not_keypad")]
     state: KeyEventState,
 }
 
+/// KEYPAD of the state of a key event as the boolean keypad field.
+/// Other bits of the state like CAPS_LOCK are not written and not read.
+mod serde_keypad_state {
+    use super::*;
+
+    pub fn serialize<S>(state: &KeyEventState, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(state.contains(KeyEventState::KEYPAD))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyEventState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let keypad = bool::deserialize(deserializer)?;
+        Ok(if keypad {
+            KeyEventState::KEYPAD
+        } else {
+            KeyEventState::NONE
+        })
+    }
+}
+
+fn is_not_keypad(state: &KeyEventState) -> bool {
+    !state.contains(KeyEventState::KEYPAD)
+}
+
+/// Key event with a note of the author like
+/// `{ "code": "s", "modifiers": "CONTROL", "note": "save" }`.
+///
+/// Unlike [`SerDeConfigKeyEvent`], which drops unknown fields like a note, the note is kept
+/// through a round trip, so tooling which rewrites a config does not lose the comments of a user.
+/// Without a note the field is not written. Kind and state of the key event are not part of the text.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::SerDeConfigKeyEventAnnotated;
+///
+/// let text = r#"{"code":"s","modifiers":"CONTROL","note":"save"}"#;
+/// let annotated: SerDeConfigKeyEventAnnotated = serde_json::from_str(text).unwrap();
+/// assert_eq!(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL), annotated.event);
+/// assert_eq!(Some("save".to_string()), annotated.note);
+/// assert_eq!(text, serde_json::to_string(&annotated).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "AnnotatedFields", into = "AnnotatedFields")]
+pub struct SerDeConfigKeyEventAnnotated {
+    pub event: KeyEvent,
+    pub note: Option<String>,
+}
+
+impl From<KeyEvent> for SerDeConfigKeyEventAnnotated {
+    fn from(event: KeyEvent) -> Self {
+        Self { event, note: None }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AnnotatedFields {
+    #[serde(with = "serde_key_code")]
+    code: KeyCode,
+    #[serde(default = "default_modifiers")]
+    #[serde(with = "serde_key_modifier")]
+    modifiers: KeyModifiers,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+impl From<AnnotatedFields> for SerDeConfigKeyEventAnnotated {
+    fn from(fields: AnnotatedFields) -> Self {
+        Self {
+            event: KeyEvent::new(fields.code, fields.modifiers),
+            note: fields.note,
+        }
+    }
+}
+
+impl From<SerDeConfigKeyEventAnnotated> for AnnotatedFields {
+    fn from(annotated: SerDeConfigKeyEventAnnotated) -> Self {
+        Self {
+            code: annotated.event.code,
+            modifiers: annotated.event.modifiers,
+            note: annotated.note,
+        }
+    }
+}
+
 fn default_modifiers() -> KeyModifiers {
     KeyModifiers::NONE
 }
-fn default_event_kind() -> KeyEventKind {
-    KeyEventKind::Press
-}
 fn default_event_state() -> KeyEventState {
     KeyEventState::NONE
 }
 
-mod serde_key_code {
-    use std::borrow::Cow;
+/// Key event with the readable serialization of [`SerDeConfigKeyEvent`]
+/// for places where `#[serde(with = "...")]` can not be used like values of a map
+/// or elements of a `Vec`.
+///
+/// It also works in a struct which is `#[serde(flatten)]` into another one, like a field with
+/// `#[serde(with = "SerDeConfigKeyEvent")]` does. RON 0.8 rejects any flattened struct,
+/// so there it needs to be a nested field.
+///
+/// A `Vec` of actions and key events keeps the order of the bindings as written in a config,
+/// unlike a `HashMap` whose entries are sorted on serialize by [`serde_keymap`](crate::serde_keymap).
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::KeyEventDef;
+///
+/// let bindings = vec![
+///     ("save".to_string(), KeyEventDef(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))),
+///     ("quit".to_string(), KeyEventDef(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE))),
+/// ];
+/// let string = serde_json::to_string(&bindings).unwrap();
+/// assert_eq!(
+///     r#"[["save",{"code":"s","modifiers":"CONTROL"}],["quit",{"code":"q","modifiers":"NONE"}]]"#,
+///     string
+/// );
+/// let back: Vec<(String, KeyEventDef)> = serde_json::from_str(&string).unwrap();
+/// assert_eq!(bindings, back);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeyEventDef(#[serde(with = "SerDeConfigKeyEvent")] pub KeyEvent);
+
+/// Queries for the modifiers like for rendering a binding in a UI.
+impl KeyEventDef {
+    pub fn has_shift(&self) -> bool {
+        self.0.modifiers.contains(KeyModifiers::SHIFT)
+    }
+
+    pub fn has_ctrl(&self) -> bool {
+        self.0.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    pub fn has_alt(&self) -> bool {
+        self.0.modifiers.contains(KeyModifiers::ALT)
+    }
+
+    pub fn has_super(&self) -> bool {
+        self.0.modifiers.contains(KeyModifiers::SUPER)
+    }
+
+    pub fn has_hyper(&self) -> bool {
+        self.0.modifiers.contains(KeyModifiers::HYPER)
+    }
+
+    pub fn has_meta(&self) -> bool {
+        self.0.modifiers.contains(KeyModifiers::META)
+    }
+
+    /// Keywords of the modifiers in the [`CANONICAL_MODIFIER_ORDER`](crate::CANONICAL_MODIFIER_ORDER) like ["CONTROL", "ALT"].
+    /// It is empty for no modifiers instead of ["NONE"].
+    pub fn modifier_names(&self) -> Vec<&'static str> {
+        if self.0.modifiers.is_empty() {
+            Vec::new()
+        } else {
+            key_modifier::bits_to_strs(&self.0.modifiers)
+        }
+    }
+}
+
+impl From<KeyEvent> for KeyEventDef {
+    fn from(event: KeyEvent) -> Self {
+        Self(event)
+    }
+}
+
+impl From<KeyEventDef> for KeyEvent {
+    fn from(event: KeyEventDef) -> Self {
+        event.0
+    }
+}
+
+pub(crate) mod serde_key_code {
+    use std::fmt;
 
     use super::*;
+    use crate::key_text::key_code::*;
     use crossterm::event::KeyCode;
-
-    static KEYWORDS: Lazy<HashMap<&str, KeyCode>> = Lazy::new(|| {
-        HashMap::from([
-            ("Backspace", KeyCode::Backspace),
-            ("Enter", KeyCode::Enter),
-            ("Left", KeyCode::Left),
-            ("Right", KeyCode::Right),
-            ("Up", KeyCode::Up),
-            ("Down", KeyCode::Down),
-            ("Home", KeyCode::Home),
-            ("End", KeyCode::End),
-            ("PageUp", KeyCode::PageUp),
-            ("PageDown", KeyCode::PageDown),
-            ("Tab", KeyCode::Tab),
-            ("BackTab", KeyCode::BackTab),
-            ("Delete", KeyCode::Delete),
-            ("Insert", KeyCode::Insert),
-            ("Null", KeyCode::Null),
-            ("Esc", KeyCode::Esc),
-            ("CapsLock", KeyCode::CapsLock),
-            ("ScrollLock", KeyCode::ScrollLock),
-            ("NumLock", KeyCode::NumLock),
-            ("PrintScreen", KeyCode::PrintScreen),
-            ("Pause", KeyCode::Pause),
-            ("Menu", KeyCode::Menu),
-            ("KeypadBegin", KeyCode::KeypadBegin),
-        ])
-    });
-
-    static KEYWORDS_REV: Lazy<HashMap<KeyCode, &str>> = Lazy::new(|| {
-        let swaped = KEYWORDS
-            .iter()
-            .map(|(&to_right, &to_left)| (to_left, to_right));
-        HashMap::from_iter(swaped)
-    });
+    use serde::de::{MapAccess, Visitor};
 
     pub fn serialize<S>(code: &KeyCode, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let content = key_code_to_text(code)?;
+        let content = key_code_to_text(code).map_err(ser::Error::custom)?;
         serializer.serialize_str(&content)
     }
 
-    fn key_code_to_text<E>(code: &KeyCode) -> Result<Cow<'static, str>, E>
-    where
-        E: ser::Error,
-    {
-        match code {
-            KeyCode::Char(char) => Ok(Cow::Owned(char.to_string())),
-            code => {
-                if let Some(value) = KEYWORDS_REV.get(code) {
-                    Ok(Cow::Borrowed(value))
-                } else {
-                    Err(ser::Error::custom(
-                        "One char must be provided or a valie keyword for a key like (Up)",
-                    ))
-                }
-            }
-        }
-    }
-
+    /// Accepts the text of a key code, a function key as number like `{ "f": 5 }`
+    /// or a char as its scalar value like `{ "raw": 57358 }`.
+    /// A number from 0 to 9 is that digit, which is how YAML or TOML read an unquoted digit.
+    ///
+    /// The raw form is for keys which have no keyword and no char to type into a config.
     pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyCode, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?.trim().to_string();
-        parse_key_code(&s)
+        deserialize_with(deserializer, ParseMode::Lenient, None)
     }
 
-    fn parse_key_code<E>(text: &str) -> Result<KeyCode, E>
+    pub(crate) fn deserialize_with<'de, D>(
+        deserializer: D,
+        mode: ParseMode,
+        fallback: Option<KeywordFallback>,
+    ) -> Result<KeyCode, D::Error>
     where
-        E: de::Error,
+        D: Deserializer<'de>,
     {
-        const ERROR_MESSAGE: &str = "One char or a certain keyword must be provided";
-
-        if text.is_empty() {
-            Err(de::Error::custom(ERROR_MESSAGE))
-        } else if text.len() == 1 {
-            let key_code = KeyCode::Char(text.chars().next().unwrap());
-            Ok(key_code)
-        } else if let Some(valid_keyword) = KEYWORDS.get(text) {
-            Ok(*valid_keyword)
+        let visitor = KeyCodeVisitor { mode, fallback };
+        // Only a self-describing format can tell text, number and object apart.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
         } else {
-            Err(de::Error::custom(ERROR_MESSAGE))
+            deserializer.deserialize_str(visitor)
+        }
+    }
+
+    /// Fields of a key code given as object instead of text.
+    #[derive(Deserialize)]
+    #[serde(field_identifier, rename_all = "lowercase")]
+    enum Field {
+        /// Function key as number like 5 for F5.
+        F,
+        /// Char as its unicode scalar value like 57358 for '\u{e00e}'.
+        Raw,
+    }
+
+    struct KeyCodeVisitor {
+        mode: ParseMode,
+        fallback: Option<KeywordFallback>,
+    }
+
+    impl<'de> Visitor<'de> for KeyCodeVisitor {
+        type Value = KeyCode;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a single character or a key name like 'Up'")
+        }
+
+        fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            parse_key_code_with_fallback(trim_config_text(text), self.mode, self.fallback)
+                .map_err(de::Error::custom)
+        }
+
+        /// A digit like `5` is a number in formats like YAML or TOML unless it is quoted.
+        fn visit_u64<E>(self, number: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u32::try_from(number)
+                .ok()
+                .and_then(|digit| char::from_digit(digit, 10))
+                .map(KeyCode::Char)
+                .ok_or_else(|| de::Error::invalid_type(de::Unexpected::Unsigned(number), &self))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let Some(field) = map.next_key()? else {
+                return Err(de::Error::invalid_value(de::Unexpected::Map, &self));
+            };
+            let code = match field {
+                Field::F => {
+                    let number: u8 = map.next_value()?;
+                    if FUNCTION_KEYS.contains(&number) {
+                        KeyCode::F(number)
+                    } else {
+                        return Err(de::Error::custom(KeyParseError::OutOfRangeFunctionKey(
+                            number,
+                        )));
+                    }
+                }
+                Field::Raw => {
+                    let scalar: u32 = map.next_value()?;
+                    match char::from_u32(scalar) {
+                        Some(raw) => KeyCode::Char(raw),
+                        None => {
+                            return Err(de::Error::custom(KeyParseError::InvalidRawCode(scalar)))
+                        }
+                    }
+                }
+            };
+            if map.next_key::<Field>()?.is_some() {
+                return Err(de::Error::custom(
+                    "a key code object has only one field, either f or raw",
+                ));
+            }
+
+            Ok(code)
         }
     }
 
@@ -128,117 +394,315 @@ mod serde_key_code {
         use super::*;
 
         #[test]
-        fn should_produce_text_as_key_code() {
-            assert_case(KeyCode::Char('a'), "a");
-            assert_case(KeyCode::Char('A'), "A");
-            assert_case(KeyCode::Char('/'), "/");
-            assert_case(KeyCode::Up, "Up");
-            assert_case(KeyCode::Enter, "Enter");
-            fn assert_case(input: KeyCode, expected: &str) {
-                let actual = key_code_to_text::<ron::Error>(&input).unwrap();
-                assert_eq!(expected, &actual);
+        fn should_expect_char_or_key_name() {
+            assert_case(
+                r#"{"code":42}"#,
+                "invalid type: integer `42`, expected a single character or a key name like 'Up' at line 1 column 10",
+            );
+            assert_case(
+                r#"{"code":{}}"#,
+                "invalid value: map, expected a single character or a key name like 'Up' at line 1 column 10",
+            );
+            assert_case(
+                r#"{"code":true}"#,
+                "invalid type: boolean `true`, expected a single character or a key name like 'Up' at line 1 column 12",
+            );
+            fn assert_case(input: &str, message: &str) {
+                let actual = serde_json::from_str::<KeyCodeOnly>(input).unwrap_err();
+                assert_eq!(message, actual.to_string());
             }
         }
         #[test]
-        fn should_parse_valid_key_code() {
-            assert_case("a", KeyCode::Char('a'));
-            assert_case("A", KeyCode::Char('A'));
-            assert_case("/", KeyCode::Char('/'));
-            assert_case("Up", KeyCode::Up);
-            assert_case("Enter", KeyCode::Enter);
-            fn assert_case(input: &str, expected: KeyCode) {
-                let actual = parse_key_code::<ron::Error>(input).unwrap();
-                assert_eq!(expected, actual);
+        fn should_keep_error_text_for_serde() {
+            let actual = ron::from_str::<KeyCodeOnly>(r#"(code: "Upp")"#).unwrap_err();
+            assert_eq!(
+                "'Upp' is not a recognized key name, did you mean 'Up'?",
+                actual.code.to_string()
+            );
+            let actual = ron::to_string(&KeyCodeOnly {
+                code: KeyCode::F(25),
+            })
+            .unwrap_err();
+            assert_eq!(
+                "F25 is not a valid function key, only F1 up to F24 are supported",
+                actual.to_string()
+            );
+        }
+        #[test]
+        fn should_accept_function_key_as_number() {
+            assert_case(r#"{ "code": "F5" }"#);
+            assert_case(r#"{ "code": { "f": 5 } }"#);
+            let actual: KeyCodeOnly = ron::from_str("(code: (f: 5))").unwrap();
+            assert_eq!(KeyCode::F(5), actual.code);
+            fn assert_case(input: &str) {
+                let actual: KeyCodeOnly = serde_json::from_str(input).unwrap();
+                assert_eq!(KeyCode::F(5), actual.code);
+            }
+        }
+        #[test]
+        fn should_deny_function_key_number_out_of_range() {
+            assert_case(r#"{ "code": { "f": 0 } }"#);
+            assert_case(r#"{ "code": { "f": 25 } }"#);
+            assert_case(r#"{ "code": { "f": 300 } }"#);
+            assert_case(r#"{ "code": { "g": 5 } }"#);
+            fn assert_case(input: &str) {
+                let actual = serde_json::from_str::<KeyCodeOnly>(input);
+                assert!(actual.is_err());
             }
         }
+        #[test]
+        fn should_accept_raw_code() {
+            let actual: KeyCodeOnly =
+                serde_json::from_str(r#"{ "code": { "raw": 57358 } }"#).unwrap();
+            assert_eq!(KeyCode::Char('\u{e00e}'), actual.code);
+            let actual: KeyCodeOnly = ron::from_str("(code: (raw: 97))").unwrap();
+            assert_eq!(KeyCode::Char('a'), actual.code);
+        }
+        #[test]
+        fn should_deny_invalid_raw_code() {
+            assert_case(
+                r#"{ "code": { "raw": 55296 } }"#,
+                "55296 is not a valid unicode scalar value for a raw key code",
+            );
+            assert_case(
+                r#"{ "code": { "raw": 1114112 } }"#,
+                "1114112 is not a valid unicode scalar value for a raw key code",
+            );
+            assert!(serde_json::from_str::<KeyCodeOnly>(r#"{ "code": { "raw": -1 } }"#).is_err());
+            assert!(
+                serde_json::from_str::<KeyCodeOnly>(r#"{ "code": { "raw": 97, "f": 5 } }"#)
+                    .is_err()
+            );
+            fn assert_case(input: &str, message: &str) {
+                let actual = serde_json::from_str::<KeyCodeOnly>(input).unwrap_err();
+                assert!(actual.to_string().starts_with(message), "{}", actual);
+            }
+        }
+        #[test]
+        fn should_serialize_function_key_as_text() {
+            let input = KeyCodeOnly {
+                code: KeyCode::F(5),
+            };
+            let actual = serde_json::to_string(&input).unwrap();
+            assert_eq!(r#"{"code":"F5"}"#, actual);
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct KeyCodeOnly {
+            #[serde(with = "super")]
+            code: KeyCode,
+        }
     }
 }
+/// Readable serialization of only the [`KeyModifiers`](crossterm::event::KeyModifiers)
+/// like "CONTROL+ALT".
+///
+/// It is used via `#[serde(with = "crossterm_serde::serde_key_modifier")]` for fields
+/// with modifiers but without a key code.
+///
+/// The case, order and delimiters of the keywords are not kept. Serialize always writes
+/// the keywords in upper case in the canonical order joined by "+", so serializing again
+/// after deserializing such an output gives the same text.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyModifiers;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+/// pub struct Mouse {
+///     #[serde(with = "crossterm_serde::serde_key_modifier")]
+///     drag: KeyModifiers,
+/// }
+///
+/// let mouse = Mouse {
+///     drag: KeyModifiers::ALT | KeyModifiers::CONTROL,
+/// };
+/// let string = serde_json::to_string(&mouse).unwrap();
+/// assert_eq!(r#"{"drag":"CONTROL+ALT"}"#, string);
+/// assert_eq!(mouse, serde_json::from_str(&string).unwrap());
+/// ```
 pub mod serde_key_modifier {
     use crossterm::event::KeyModifiers;
+    use serde::de::{DeserializeSeed, MapAccess, SeqAccess, Visitor};
+    use std::fmt;
 
     use super::*;
-    use serde::{de, Deserialize, Deserializer, Serializer};
-
-    const SEPERATOR: &str = "+";
-    const NONE: &str = "NONE";
-
-    const SHIFT: &str = "SHIFT";
-    const CONTROL: &str = "CONTROL";
-    const SUPER: &str = "SUPER";
-    const ALT: &str = "ALT";
-    const HYPER: &str = "HYPER";
-    const META: &str = "META";
-
-    static KEYWORD: Lazy<HashMap<&str, KeyModifiers>> = Lazy::new(|| {
-        HashMap::from([
-            (SHIFT, KeyModifiers::SHIFT),
-            (CONTROL, KeyModifiers::CONTROL),
-            (ALT, KeyModifiers::ALT),
-            (SUPER, KeyModifiers::SUPER),
-            (HYPER, KeyModifiers::HYPER),
-            (META, KeyModifiers::META),
-            (NONE, KeyModifiers::NONE),
-        ])
-    });
-
-    macro_rules! push_if_contains {
-        ($m:ident, $v:ident, $e:ident) => {
-            if $m.contains(KeyModifiers::$e) {
-                $v.push(stringify!($e));
-            }
-        };
-    }
+    pub use crate::key_text::key_modifier::CANONICAL_MODIFIER_ORDER;
+    use crate::key_text::key_modifier::*;
 
-    fn bits_to_strs(modif: &KeyModifiers) -> Vec<&str> {
-        let mut to_return = Vec::new();
-        push_if_contains!(modif, to_return, ALT);
-        push_if_contains!(modif, to_return, CONTROL);
-        push_if_contains!(modif, to_return, SHIFT);
-        push_if_contains!(modif, to_return, SUPER);
-        push_if_contains!(modif, to_return, HYPER);
-        if modif.is_empty() {
-            to_return.push(NONE);
-        }
-        to_return
-    }
+    /// Length of the longest joined text "SHIFT+CONTROL+ALT+SUPER+HYPER+META+0x40+0x80".
+    const MAX_JOINED_LEN: usize = 44;
 
+    /// Joins the keywords in a buffer on the stack, so no string is allocated.
     pub fn serialize<S>(modifier: &KeyModifiers, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let seq = bits_to_strs(modifier);
-        serializer.serialize_str(&seq.join(SEPERATOR))
+        let mut buffer = [0; MAX_JOINED_LEN];
+        let mut len = 0;
+        for (index, keyword) in keywords(modifier).enumerate() {
+            if index > 0 {
+                buffer[len..len + SEPERATOR.len()].copy_from_slice(SEPERATOR.as_bytes());
+                len += SEPERATOR.len();
+            }
+            buffer[len..len + keyword.len()].copy_from_slice(keyword.as_bytes());
+            len += keyword.len();
+        }
+        let joined = std::str::from_utf8(&buffer[..len]).expect("Keywords are only ASCII");
+        serializer.serialize_str(joined)
     }
 
+    /// Accepts the keywords joined by "+" like "ALT+CONTROL",
+    /// a sequence of keywords like ["ALT", "CONTROL"]
+    /// or an object of flags like `{ "ctrl": true, "alt": true }`.
+    /// A keyword which is not in the object or is false is not pressed.
+    /// The case of the keywords does not matter like "alt" or "Alt".
     pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyModifiers, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let text = String::deserialize(deserializer)?;
-        parse_key_modifier(&text)
+        deserialize_with(deserializer, ParseMode::Lenient, BlankModifiers::Reject)
     }
 
-    fn parse_key_modifier<E>(text: &str) -> Result<KeyModifiers, E>
+    pub(crate) fn deserialize_with<'de, D>(
+        deserializer: D,
+        mode: ParseMode,
+        blank: BlankModifiers,
+    ) -> Result<KeyModifiers, D::Error>
     where
-        E: de::Error,
+        D: Deserializer<'de>,
     {
-        let text = text.trim();
+        let visitor = KeyModifiersVisitor { mode, blank };
+        // Only a self-describing format can tell text, sequence and object apart.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_str(visitor)
+        }
+    }
+
+    struct KeyModifiersVisitor {
+        mode: ParseMode,
+        blank: BlankModifiers,
+    }
+
+    impl<'de> Visitor<'de> for KeyModifiersVisitor {
+        type Value = KeyModifiers;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(
+                "keywords joined by + like ALT+CONTROL, a sequence of keywords or an object of flags",
+            )
+        }
+
+        fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if self.blank == BlankModifiers::AsNone && trim_config_text(text).is_empty() {
+                return Ok(KeyModifiers::NONE);
+            }
+            parse_key_modifier_with(text, self.mode).map_err(de::Error::custom)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut result = KeyModifiers::NONE;
+            let mut has_none = false;
+            let mut count = 0;
+            while let Some(next) = seq.next_element_seed(KeywordSeed { mode: self.mode })? {
+                has_none |= next.is_empty();
+                count += 1;
+                result |= next;
+            }
+            if count == 0 {
+                return match self.blank {
+                    BlankModifiers::AsNone => Ok(KeyModifiers::NONE),
+                    BlankModifiers::Reject => Err(de::Error::custom(KeyParseError::EmptyModifiers)),
+                };
+            }
+            self.deny_none_with_others(has_none, count, result)
+        }
 
-        if text.is_empty() {
-            return Err(de::Error::custom(
-                "Need to provide at least keyword for the key modifier",
-            ));
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut result = KeyModifiers::NONE;
+            let mut has_none = false;
+            let mut count = 0;
+            while let Some(next) = map.next_key_seed(KeywordSeed { mode: self.mode })? {
+                if map.next_value::<bool>()? {
+                    has_none |= next.is_empty();
+                    count += 1;
+                    result |= next;
+                }
+            }
+            // An object without a true flag is NONE like the one written by ModifiersStyle::Flags.
+            self.deny_none_with_others(has_none, count, result)
         }
+    }
+
+    impl KeyModifiersVisitor {
+        /// NONE next to another keyword is a no-op and most likely a mistake in strict mode
+        /// like in the joined text. Only NONE itself results in no bits.
+        fn deny_none_with_others<E>(
+            &self,
+            has_none: bool,
+            count: usize,
+            result: KeyModifiers,
+        ) -> Result<KeyModifiers, E>
+        where
+            E: de::Error,
+        {
+            if self.mode == ParseMode::Strict && has_none && count > 1 {
+                let text = std::iter::once(NONE)
+                    .chain(keywords(&result))
+                    .collect::<Vec<&str>>()
+                    .join(SEPERATOR);
+                Err(de::Error::custom(KeyParseError::NoneWithOtherModifiers(
+                    text,
+                )))
+            } else {
+                Ok(result)
+            }
+        }
+    }
+
+    /// Parses one keyword of a sequence directly from the text of the format
+    /// without an owned string in between.
+    struct KeywordSeed {
+        mode: ParseMode,
+    }
+
+    impl<'de> DeserializeSeed<'de> for KeywordSeed {
+        type Value = KeyModifiers;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
 
-        let mut result = KeyModifiers::NONE;
-        for next in text.split(SEPERATOR) {
-            let keyword = KEYWORD
-                .get(next)
-                .ok_or_else(|| de::Error::custom(format!("{} is not a valid keyword", next)))?;
-            result |= *keyword;
+    impl<'de> Visitor<'de> for KeywordSeed {
+        type Value = KeyModifiers;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a modifier keyword like ALT")
         }
 
-        Ok(result)
+        fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            parse_keyword(trim_config_text(text), self.mode).map_err(de::Error::custom)
+        }
     }
 
     #[cfg(test)]
@@ -246,44 +710,264 @@ pub mod serde_key_modifier {
 
         use super::*;
         #[test]
-        fn should_accept_valid_key_modifiers() {
+        fn should_accept_sequence_of_keywords() {
             assert_case(
-                format!("{}+{}", ALT, CONTROL),
-                KeyModifiers::CONTROL | KeyModifiers::ALT,
+                r#"{ "modifiers": ["ALT", "CONTROL"] }"#,
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
             );
+            assert_case(r#"{ "modifiers": ["NONE"] }"#, KeyModifiers::NONE);
             assert_case(
-                format!("{}+{}+{}", META, NONE, SUPER),
-                KeyModifiers::META | KeyModifiers::SUPER,
+                r#"{ "modifiers": "ALT+CONTROL" }"#,
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
             );
-            assert_case(format!("{}", NONE), KeyModifiers::NONE);
-            fn assert_case(input: String, expected: KeyModifiers) {
-                let actual: Result<KeyModifiers, ron::Error> = parse_key_modifier(&input);
-                assert_eq!(expected, actual.unwrap());
+            fn assert_case(input: &str, expected: KeyModifiers) {
+                let actual: ModifiersOnly = serde_json::from_str(input).unwrap();
+                assert_eq!(expected, actual.modifiers);
             }
         }
         #[test]
-        fn should_deny_invalid_key_modifiers() {
-            assert_case(format!(""));
-            assert_case(format!("AL"));
-            assert_case(format!("ALT+Z"));
-            fn assert_case(input: String) {
-                let actual: Result<KeyModifiers, ron::Error> = parse_key_modifier(&input);
-                assert!(actual.is_err());
+        fn should_check_sequence_and_flags_like_joined_text() {
+            fn read(
+                input: &str,
+                mode: ParseMode,
+                blank: BlankModifiers,
+            ) -> Result<KeyModifiers, String> {
+                deserialize_with(&mut serde_json::Deserializer::from_str(input), mode, blank)
+                    .map_err(|error| match error.to_string().split_once(" at line") {
+                        Some((message, _)) => message.to_string(),
+                        None => error.to_string(),
+                    })
+            }
+            let none_with_others = KeyParseError::NoneWithOtherModifiers(String::new()).to_string();
+            for input in [
+                r#"["NONE", "CONTROL"]"#,
+                r#"{ "NONE": true, "ctrl": true }"#,
+            ] {
+                assert_eq!(
+                    Err(none_with_others.clone()),
+                    read(input, ParseMode::Strict, BlankModifiers::Reject),
+                    "{}",
+                    input
+                );
+                assert_eq!(
+                    Ok(KeyModifiers::CONTROL),
+                    read(input, ParseMode::Lenient, BlankModifiers::Reject),
+                    "{}",
+                    input
+                );
             }
+            assert_eq!(
+                Ok(KeyModifiers::CONTROL),
+                read(
+                    r#"{ "NONE": false, "ctrl": true }"#,
+                    ParseMode::Strict,
+                    BlankModifiers::Reject
+                )
+            );
+
+            let empty = KeyParseError::EmptyModifiers.to_string();
+            assert_eq!(
+                Err(empty),
+                read("[]", ParseMode::Lenient, BlankModifiers::Reject)
+            );
+            assert_eq!(
+                Ok(KeyModifiers::NONE),
+                read("[]", ParseMode::Strict, BlankModifiers::AsNone)
+            );
+            assert_eq!(
+                Ok(KeyModifiers::NONE),
+                read("{}", ParseMode::Strict, BlankModifiers::Reject)
+            );
+        }
+        #[test]
+        fn should_deny_invalid_keyword_in_sequence() {
+            let actual = serde_json::from_str::<ModifiersOnly>(r#"{ "modifiers": ["ALT", "Z"] }"#);
+            assert!(actual.is_err());
+        }
+        #[derive(Debug, Deserialize)]
+        struct ModifiersOnly {
+            #[serde(with = "super")]
+            modifiers: KeyModifiers,
+        }
+        #[test]
+        fn should_serialize_like_joined_keywords() {
+            let mut longest = 0;
+            for input in all_modifiers() {
+                let expected = bits_to_strs(&input).join(SEPERATOR);
+                let mut actual = Vec::new();
+                serialize(&input, &mut serde_json::Serializer::new(&mut actual)).unwrap();
+                assert_eq!(
+                    format!("\"{}\"", expected),
+                    String::from_utf8(actual).unwrap()
+                );
+                longest = longest.max(expected.len());
+            }
+            assert!(longest <= MAX_JOINED_LEN);
+            assert_eq!(
+                "SHIFT+CONTROL+ALT+SUPER+HYPER+META+0x40+0x80".len(),
+                MAX_JOINED_LEN
+            );
+        }
+        /// Every bit combination, including bits without a flag if crossterm can hold them.
+        #[cfg(feature = "crossterm-0_27")]
+        fn all_modifiers() -> impl Iterator<Item = KeyModifiers> {
+            (0..=u8::MAX).map(KeyModifiers::from_bits_retain)
+        }
+        #[cfg(not(feature = "crossterm-0_27"))]
+        fn all_modifiers() -> impl Iterator<Item = KeyModifiers> {
+            (0..=u8::MAX).filter_map(KeyModifiers::from_bits)
         }
+        #[cfg(feature = "crossterm-0_27")]
+        #[test]
+        fn should_keep_unknown_bits() {
+            let input = KeyModifiers::from_bits_retain(KeyModifiers::CONTROL.bits() | 0x40);
+            let text = bits_to_strs(&input).join(SEPERATOR);
+            assert_eq!("CONTROL+0x40", text);
+            let actual = parse_key_modifier(&text).unwrap();
+            assert_eq!(input.bits(), actual.bits());
+            let mut buffer = Vec::new();
+            serialize(&input, &mut serde_json::Serializer::new(&mut buffer)).unwrap();
+            assert_eq!(r#""CONTROL+0x40""#, String::from_utf8(buffer).unwrap());
+            assert_eq!(
+                0xc0,
+                parse_key_modifier("0xc0").unwrap().bits(),
+                "Several unknown bits in one token"
+            );
+        }
+        #[cfg(not(feature = "crossterm-0_27"))]
+        #[test]
+        fn should_deny_unknown_bits_without_crossterm_0_27() {
+            assert_eq!(
+                Err(KeyParseError::UnknownModifierBits("0x40".to_string())),
+                parse_key_modifier("CONTROL+0x40")
+            );
+            let actual = serde_json::from_str::<ModifiersOnly>(r#"{ "modifiers": "0xc0" }"#);
+            assert!(actual.is_err());
+        }
+        #[test]
+        fn should_deny_hex_in_strict_mode() {
+            assert_eq!(
+                Err(KeyParseError::HexModifier("0x40".to_string())),
+                parse_key_modifier_with("CONTROL+0x40", ParseMode::Strict)
+            );
+            let actual = serde_json::from_str::<ModifiersOnly>(r#"{ "modifiers": ["0x40"] }"#);
+            let strict = KeyParseError::HexModifier("0x40".to_string()).to_string();
+            assert!(
+                actual.map_or_else(|error| !error.to_string().contains(&strict), |_| true),
+                "A sequence is lenient by default"
+            );
+        }
+    }
+}
+
+/// Readable serialization of only the [`KeyEventKind`](crossterm::event::KeyEventKind)
+/// like "Press", "Repeat" or "Release".
+///
+/// It is used via `#[serde(with = "crossterm_serde::serde_key_event_kind")]`.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyEventKind;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+/// pub struct Trigger {
+///     #[serde(with = "crossterm_serde::serde_key_event_kind")]
+///     on: KeyEventKind,
+/// }
+///
+/// let trigger = Trigger {
+///     on: KeyEventKind::Release,
+/// };
+/// let string = serde_json::to_string(&trigger).unwrap();
+/// assert_eq!(r#"{"on":"Release"}"#, string);
+/// assert_eq!(trigger, serde_json::from_str(&string).unwrap());
+/// ```
+pub mod serde_key_event_kind {
+    use crossterm::event::KeyEventKind;
+    use serde::de::Visitor;
+    use std::fmt;
+
+    use super::*;
+
+    const KINDS: &[(&str, KeyEventKind)] = &[
+        ("Press", KeyEventKind::Press),
+        ("Repeat", KeyEventKind::Repeat),
+        ("Release", KeyEventKind::Release),
+    ];
+
+    pub fn serialize<S>(kind: &KeyEventKind, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(kind_to_text(kind))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyEventKind, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(KeyEventKindVisitor)
+    }
+
+    struct KeyEventKindVisitor;
+
+    impl<'de> Visitor<'de> for KeyEventKindVisitor {
+        type Value = KeyEventKind;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("one of the key event kinds Press, Repeat or Release")
+        }
+
+        fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            parse_key_event_kind(trim_config_text(text)).map_err(de::Error::custom)
+        }
+    }
+
+    pub(crate) fn kind_to_text(kind: &KeyEventKind) -> &'static str {
+        KINDS
+            .iter()
+            .find(|(_, next)| next == kind)
+            .map(|(text, _)| *text)
+            .expect("Every key event kind has a text")
+    }
+
+    pub(crate) fn parse_key_event_kind(text: &str) -> Result<KeyEventKind, KeyParseError> {
+        KINDS
+            .iter()
+            .find(|(next, _)| *next == text)
+            .map(|(_, kind)| *kind)
+            .ok_or_else(|| KeyParseError::UnknownEventKind(text.to_string()))
+    }
+
+    #[cfg(test)]
+    mod testing {
+        use super::*;
+
         #[test]
-        fn should_convert_bits_strs() {
-            let expected = &[ALT, CONTROL];
-            let input = KeyModifiers::ALT | KeyModifiers::CONTROL;
-            let actual = bits_to_strs(&input);
-            assert_eq!(expected.as_slice(), actual.as_slice());
+        fn should_round_trip_every_kind() {
+            for (_, kind) in KINDS {
+                let text = kind_to_text(kind);
+                assert_eq!(*kind, parse_key_event_kind(text).unwrap());
+            }
         }
         #[test]
-        fn should_convert_none_to_one_none() {
-            let expected = &[NONE];
-            let input = KeyModifiers::empty();
-            let actual = bits_to_strs(&input);
-            assert_eq!(expected.as_slice(), actual.as_slice());
+        fn should_deny_unknown_kind() {
+            #[derive(Debug, Deserialize)]
+            struct KindOnly {
+                #[serde(with = "super")]
+                #[allow(dead_code)]
+                kind: KeyEventKind,
+            }
+            let actual = serde_json::from_str::<KindOnly>(r#"{"kind":"Pressed"}"#).unwrap_err();
+            assert_eq!(
+                "Pressed is not a valid key event kind, only Press, Repeat and Release are supported at line 1 column 17",
+                actual.to_string()
+            );
         }
     }
 }
@@ -291,6 +975,7 @@ pub mod serde_key_modifier {
 #[cfg(test)]
 mod testing {
     use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
     #[derive(Debug, Serialize, Deserialize)]
     pub struct KeyBoard {
         #[serde(with = "SerDeConfigKeyEvent")]
@@ -343,4 +1028,417 @@ mod testing {
         let actual: KeyBoard = ron::from_str(RON_INPUT).unwrap();
         insta::assert_ron_snapshot!(actual);
     }
+    #[test]
+    fn test_deserialize_compact_and_structured_form() {
+        #[derive(Debug, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            key: KeyEvent,
+        }
+        let expected = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        let structured: Binding =
+            serde_json::from_str(r#"{"key":{"code":"a","modifiers":"CONTROL"}}"#).unwrap();
+        let compact: Binding = serde_json::from_str(r#"{"key":"CTRL+a"}"#).unwrap();
+        assert_eq!(expected, structured.key);
+        assert_eq!(expected, compact.key);
+        let compact: Binding = ron::from_str(r#"(key: "CTRL+a")"#).unwrap();
+        assert_eq!(expected, compact.key);
+    }
+    #[test]
+    fn test_round_trip_via_bincode() {
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            key: KeyEvent,
+            #[serde(with = "serde_key_code")]
+            code: KeyCode,
+            #[serde(with = "serde_key_modifier")]
+            modifiers: KeyModifiers,
+            #[serde(with = "serde_key_event_kind")]
+            kind: KeyEventKind,
+        }
+        let input = Binding {
+            key: KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            code: KeyCode::F(5),
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Release,
+        };
+        let bytes = bincode::serialize(&input).unwrap();
+        let actual: Binding = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(input, actual);
+    }
+    #[test]
+    fn test_round_trip_with_kind() {
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEventWithKind")]
+            key: KeyEvent,
+        }
+        let input = Binding {
+            key: KeyEvent::new_with_kind_and_state(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL,
+                KeyEventKind::Release,
+                KeyEventState::CAPS_LOCK,
+            ),
+        };
+        let text = ron::to_string(&input).unwrap();
+        assert_eq!(
+            r#"(key:(code:"a",modifiers:"CONTROL",kind:"Release"))"#, text,
+            "Without KEYPAD the state is not written"
+        );
+        let actual: Binding = ron::from_str(&text).unwrap();
+        assert_eq!(input.key.code, actual.key.code);
+        assert_eq!(input.key.modifiers, actual.key.modifiers);
+        assert_eq!(KeyEventKind::Release, actual.key.kind);
+        assert_eq!(KeyEventState::NONE, actual.key.state);
+    }
+    #[test]
+    fn test_round_trip_keypad_with_kind() {
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEventWithKind")]
+            key: KeyEvent,
+        }
+        let input = Binding {
+            key: KeyEvent::new_with_kind_and_state(
+                KeyCode::Enter,
+                KeyModifiers::NONE,
+                KeyEventKind::Press,
+                KeyEventState::KEYPAD | KeyEventState::NUM_LOCK,
+            ),
+        };
+        let text = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            r#"{"key":{"code":"Enter","modifiers":"NONE","kind":"Press","keypad":true}}"#,
+            text
+        );
+        let actual: Binding = serde_json::from_str(&text).unwrap();
+        assert_eq!(KeyEventState::KEYPAD, actual.key.state);
+        assert!(crate::key_event_matches_keypad(&actual.key, &input.key));
+        let main = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert!(!crate::key_event_matches_keypad(&actual.key, &main));
+    }
+    #[test]
+    fn test_require_kind_with_kind() {
+        #[derive(Debug, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEventWithKind")]
+            _key: KeyEvent,
+        }
+        let actual = serde_json::from_str::<Binding>(r#"{"_key":{"code":"a"}}"#).unwrap_err();
+        assert_eq!(
+            "missing field `kind` at line 1 column 20",
+            actual.to_string()
+        );
+    }
+    #[test]
+    fn test_keep_order_of_bindings_in_vec() {
+        let bindings: Vec<(String, KeyEventDef)> = ["quit", "save", "open", "close"]
+            .iter()
+            .zip(['q', 's', 'o', 'c'])
+            .map(|(action, code)| {
+                let event = KeyEvent::new(KeyCode::Char(code), KeyModifiers::CONTROL);
+                (action.to_string(), KeyEventDef(event))
+            })
+            .collect();
+        let text = ron::to_string(&bindings).unwrap();
+        assert_eq!(
+            r#"[("quit",(code:"q",modifiers:"CONTROL")),("save",(code:"s",modifiers:"CONTROL")),("open",(code:"o",modifiers:"CONTROL")),("close",(code:"c",modifiers:"CONTROL"))]"#,
+            text
+        );
+        let actual: Vec<(String, KeyEventDef)> = ron::from_str(&text).unwrap();
+        assert_eq!(bindings, actual);
+        assert_eq!(text, ron::to_string(&actual).unwrap());
+    }
+    #[test]
+    fn test_deserialize_in_flattened_struct() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+        struct Keys {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            save: KeyEvent,
+            quit: KeyEventDef,
+        }
+        #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+        struct Config {
+            name: String,
+            #[serde(flatten)]
+            keys: Keys,
+        }
+        let expected = Config {
+            name: "editor".to_string(),
+            keys: Keys {
+                save: KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+                quit: KeyEventDef(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE)),
+            },
+        };
+        let json = r#"{"name":"editor","save":{"code":"s","modifiers":"CONTROL"},"quit":"q"}"#;
+        let actual: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(expected, actual);
+        let json = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected, serde_json::from_str(&json).unwrap());
+        let json =
+            r#"{"name":"editor","save":"CONTROL+s","quit":{"code":"q","modifiers":["ALTT"]}}"#;
+        assert!(serde_json::from_str::<Config>(json).is_err());
+        let json = r#"{"name":"editor","save":"CONTROL+s","quit":{"code":{"f":5}}}"#;
+        let actual: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(KeyCode::F(5), actual.keys.quit.0.code);
+    }
+    #[test]
+    fn test_strip_bom_and_carriage_return() {
+        #[derive(Debug, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            key: KeyEvent,
+        }
+        let expected = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        assert_case(
+            r#"{"key":{"code":"a","modifiers":"\ufeffCONTROL"}}"#,
+            expected,
+        );
+        assert_case(r#"{"key":{"code":"a","modifiers":"CONTROL\r"}}"#, expected);
+        assert_case(
+            r#"{"key":{"code":"\ufeffa\r\n","modifiers":"CONTROL"}}"#,
+            expected,
+        );
+        assert_case(r#"{"key":"\ufeffCONTROL+a\r"}"#, expected);
+        assert_case(
+            r#"{"key":{"code":"a","modifiers":["\ufeffCONTROL","ALT\r"]}}"#,
+            KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            ),
+        );
+        assert_eq!(
+            Ok(KeyModifiers::CONTROL),
+            key_modifier::parse_key_modifier("\u{feff}CONTROL")
+        );
+        assert_eq!(
+            Ok(KeyModifiers::CONTROL),
+            key_modifier::parse_key_modifier("CONTROL\r")
+        );
+        fn assert_case(input: &str, expected: KeyEvent) {
+            let actual: Binding = serde_json::from_str(input).unwrap();
+            assert_eq!(expected, actual.key);
+        }
+    }
+    #[test]
+    fn test_keep_note_of_annotated_key_event() {
+        let input = SerDeConfigKeyEventAnnotated {
+            event: KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            note: Some("save".to_string()),
+        };
+        let text = ron::to_string(&input).unwrap();
+        assert_eq!(r#"(code:"a",modifiers:"CONTROL",note:Some("save"))"#, text);
+        assert_eq!(input, ron::from_str(&text).unwrap());
+
+        let actual: SerDeConfigKeyEventAnnotated =
+            serde_json::from_str(r#"{ "code": "a", "modifiers": "CTRL", "note": "save" }"#)
+                .unwrap();
+        assert_eq!(input, actual);
+        assert_eq!(
+            r#"{"code":"a","modifiers":"CONTROL","note":"save"}"#,
+            serde_json::to_string(&actual).unwrap()
+        );
+    }
+    #[test]
+    fn test_skip_missing_note_of_annotated_key_event() {
+        let actual: SerDeConfigKeyEventAnnotated =
+            serde_json::from_str(r#"{ "code": "Up" }"#).unwrap();
+        assert_eq!(
+            SerDeConfigKeyEventAnnotated::from(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+            actual
+        );
+        assert_eq!(
+            r#"{"code":"Up","modifiers":"NONE"}"#,
+            serde_json::to_string(&actual).unwrap()
+        );
+    }
+    #[test]
+    fn test_query_modifiers_of_key_event_def() {
+        let event = KeyEventDef(KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        ));
+        assert!(event.has_ctrl());
+        assert!(event.has_alt());
+        assert!(!event.has_shift());
+        assert!(!event.has_super());
+        assert_eq!(vec!["CONTROL", "ALT"], event.modifier_names());
+
+        let event = KeyEventDef(KeyEvent::new(
+            KeyCode::Up,
+            KeyModifiers::SHIFT | KeyModifiers::SUPER | KeyModifiers::META,
+        ));
+        assert!(event.has_shift());
+        assert!(event.has_super());
+        assert!(event.has_meta());
+        assert!(!event.has_hyper());
+        assert_eq!(vec!["SHIFT", "SUPER", "META"], event.modifier_names());
+
+        let event = KeyEventDef(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert!(event.modifier_names().is_empty());
+    }
+    #[test]
+    fn test_serialize_is_idempotent_after_normalization() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            key: KeyEvent,
+        }
+        assert_case(r#"{"code":"a","modifiers":"alt+CONTROL"}"#, "CONTROL+ALT");
+        assert_case(r#"{"code":"a","modifiers":" Alt , ctrl "}"#, "CONTROL+ALT");
+        assert_case(
+            r#"{"code":"a","modifiers":"super shift  alt"}"#,
+            "SHIFT+ALT+SUPER",
+        );
+        assert_case(r#"{"code":"a","modifiers":["meta","Hyper"]}"#, "HYPER+META");
+        assert_case(r#"{"code":"a","modifiers":"OPTION+COMMAND"}"#, "ALT+SUPER");
+        assert_case(r#"{"code":"a","modifiers":"none"}"#, "NONE");
+        assert_case(r#"{"code":"a"}"#, "NONE");
+        assert_case(r#""ctrl+alt+a""#, "CONTROL+ALT");
+        fn assert_case(input: &str, modifiers: &str) {
+            let once = round_trip(&format!(r#"{{"key":{}}}"#, input));
+            let twice = round_trip(&once);
+            let expected = format!(r#"{{"key":{{"code":"a","modifiers":"{}"}}}}"#, modifiers);
+            assert_eq!(expected, once);
+            assert_eq!(once, twice, "Input: {}", input);
+        }
+        fn round_trip(text: &str) -> String {
+            let binding: Binding = serde_json::from_str(text).unwrap();
+            serde_json::to_string(&binding).unwrap()
+        }
+    }
+    #[test]
+    fn test_round_trip_toml_tables() {
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct Config {
+            name: String,
+            keyboard: KeyBoard,
+        }
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct KeyBoard {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            move_up: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            comment: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            quote: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            save: KeyEvent,
+        }
+        let input = r##"
+name = "editor"
+
+[keyboard.move_up]
+code = "a"
+modifiers = "ALT+CONTROL"
+
+[keyboard.comment]
+code = "#"
+
+[keyboard.quote]
+code = '"'
+modifiers = "SHIFT"
+
+[keyboard.save]
+code = "F5"
+modifiers = "NONE"
+"##;
+        let expected = Config {
+            name: "editor".to_string(),
+            keyboard: KeyBoard {
+                move_up: KeyEvent::new(
+                    KeyCode::Char('a'),
+                    KeyModifiers::ALT | KeyModifiers::CONTROL,
+                ),
+                comment: KeyEvent::new(KeyCode::Char('#'), KeyModifiers::NONE),
+                quote: KeyEvent::new(KeyCode::Char('"'), KeyModifiers::SHIFT),
+                save: KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE),
+            },
+        };
+        let actual: Config = toml::from_str(input).unwrap();
+        assert_eq!(expected, actual);
+
+        let text = toml::to_string(&actual).unwrap();
+        assert!(
+            text.contains("[keyboard.move_up]\ncode = \"a\"\nmodifiers = \"CONTROL+ALT\"\n"),
+            "{}",
+            text
+        );
+        assert_eq!(expected, toml::from_str(&text).unwrap());
+    }
+    #[test]
+    fn should_round_trip_punctuation_key_codes_in_yaml() {
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct Binding {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            key: KeyEvent,
+        }
+        for symbol in [
+            ':', '#', '-', '?', '\'', '"', '&', '*', '!', '|', '>', '%', '@', '`',
+        ] {
+            let binding = Binding {
+                key: KeyEvent::new(KeyCode::Char(symbol), KeyModifiers::ALT),
+            };
+            let yaml = serde_yaml::to_string(&binding).unwrap();
+            assert_eq!(
+                binding,
+                serde_yaml::from_str(&yaml).unwrap(),
+                "{:?} written as {}",
+                symbol,
+                yaml
+            );
+        }
+    }
+    #[test]
+    fn should_read_natural_yaml() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct KeyBoard {
+            #[serde(with = "SerDeConfigKeyEvent")]
+            command: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            comment: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            search: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            mark: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            zoom_out: KeyEvent,
+            #[serde(with = "SerDeConfigKeyEvent")]
+            save: KeyEvent,
+        }
+        let input = r##"
+command:
+  code: ":"
+  modifiers: NONE
+comment:
+  code: "#"
+  modifiers: CONTROL
+search:
+  code: '?'
+  modifiers: SHIFT
+mark:
+  code: "'"
+zoom_out:
+  code: "-"
+  modifiers: ALT+CONTROL
+save:
+  code: 5
+  modifiers: |-
+    CONTROL
+"##;
+        let expected = KeyBoard {
+            command: KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE),
+            comment: KeyEvent::new(KeyCode::Char('#'), KeyModifiers::CONTROL),
+            search: KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT),
+            mark: KeyEvent::new(KeyCode::Char('\''), KeyModifiers::NONE),
+            zoom_out: KeyEvent::new(
+                KeyCode::Char('-'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL,
+            ),
+            save: KeyEvent::new(KeyCode::Char('5'), KeyModifiers::CONTROL),
+        };
+        assert_eq!(expected, serde_yaml::from_str(input).unwrap());
+    }
 }
@@ -0,0 +1,169 @@
+use crate::key_text::key_modifier;
+
+/// Creates a key event from its compact form like "CTRL+ALT+x" for key events in code.
+///
+/// The grammar is the same as the one of [`key_event_from_str`](crate::key_event_from_str),
+/// so default keymaps in code and configs look the same.
+/// The modifier keywords are checked at compile time.
+/// The key code is checked when the macro is evaluated and an invalid one panics.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::key;
+///
+/// assert_eq!(
+///     KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT),
+///     key!("CTRL+ALT+x")
+/// );
+/// assert_eq!(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE), key!("Up"));
+/// ```
+///
+/// An unknown modifier keyword does not compile.
+///
+/// ```compile_fail
+/// let _ = crossterm_serde::key!("CRTL+x");
+/// ```
+#[macro_export]
+macro_rules! key {
+    ($text:literal) => {{
+        const _: () = $crate::__private::check_modifier_keywords($text);
+        match $crate::key_event_from_str($text) {
+            Ok(event) => event,
+            Err(error) => panic!("key!({:?}) is not a valid key event: {}", $text, error),
+        }
+    }};
+}
+
+/// Panics at compile time if a modifier keyword of a compact key event is unknown.
+///
+/// Splits the text like [`key_event_from_str`](crate::key_event_from_str) with lenient
+/// delimiters. Only ASCII whitespace is treated as whitespace here since this runs in const.
+pub const fn check_modifier_keywords(text: &str) {
+    let bytes = text.as_bytes();
+    let (start, text_end) = trimmed(bytes);
+    let end = modifiers_end(bytes, start, text_end);
+    // The key code comes before the modifiers like "a+CTRL" if the last part is a keyword.
+    let code_start = if end < text_end && bytes[end] == key_modifier::SEPERATOR.as_bytes()[0] {
+        end + 1
+    } else {
+        end
+    };
+    let mut key_codes_left = if end > start && is_keyword(bytes, code_start, text_end) {
+        1
+    } else {
+        0
+    };
+
+    let mut token_start = start;
+    let mut index = start;
+    while index <= end {
+        if index == end || is_delimiter(bytes[index]) {
+            if index > token_start && !is_keyword(bytes, token_start, index) {
+                if key_codes_left == 0 {
+                    panic!(
+                        "Unknown modifier keyword in key!, valid are \
+                         SHIFT, CONTROL, CTRL, ALT, SUPER, HYPER, META, COMMAND, OPTION, WIN and NONE"
+                    );
+                }
+                key_codes_left -= 1;
+            }
+            token_start = index + 1;
+        }
+        index += 1;
+    }
+}
+
+const fn trimmed(bytes: &[u8]) -> (usize, usize) {
+    let mut start = 0;
+    let mut end = bytes.len();
+    while start < end && bytes[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    (start, end)
+}
+
+/// End of the modifiers before the key code. The key code "+" itself is not a separator.
+const fn modifiers_end(bytes: &[u8], start: usize, end: usize) -> usize {
+    let separator = key_modifier::SEPERATOR.as_bytes()[0];
+    let len = end - start;
+    if len == 1 && bytes[start] == separator {
+        return start;
+    }
+    if len >= 2 && bytes[end - 1] == separator && bytes[end - 2] == separator {
+        return end - 2;
+    }
+    let mut index = end;
+    while index > start {
+        index -= 1;
+        if bytes[index] == separator {
+            return index;
+        }
+    }
+    start
+}
+
+const fn is_delimiter(byte: u8) -> bool {
+    let mut index = 0;
+    while index < key_modifier::LENIENT_DELIMITERS.len() {
+        if byte == key_modifier::LENIENT_DELIMITERS[index] as u8 {
+            return true;
+        }
+        index += 1;
+    }
+    byte.is_ascii_whitespace()
+}
+
+const fn is_keyword(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut keyword_index = 0;
+    while keyword_index < key_modifier::KEYWORD_NAMES.len() {
+        let keyword = key_modifier::KEYWORD_NAMES[keyword_index].as_bytes();
+        if keyword.len() == end - start {
+            let mut index = 0;
+            while index < keyword.len()
+                && keyword[index].eq_ignore_ascii_case(&bytes[start + index])
+            {
+                index += 1;
+            }
+            if index == keyword.len() {
+                return true;
+            }
+        }
+        keyword_index += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::key_event_from_str;
+
+    #[test]
+    fn should_expand_like_compact_form() {
+        assert_eq!(
+            key_event_from_str("CTRL+ALT+x").unwrap(),
+            key!("CTRL+ALT+x")
+        );
+        assert_eq!(key_event_from_str("Up").unwrap(), key!("Up"));
+        assert_eq!(
+            key_event_from_str("alt, shift+F5").unwrap(),
+            key!("alt, shift+F5")
+        );
+        assert_eq!(key_event_from_str("+").unwrap(), key!("+"));
+        assert_eq!(key_event_from_str("CONTROL++").unwrap(), key!("CONTROL++"));
+        assert_eq!(key_event_from_str("a+CTRL").unwrap(), key!("a+CTRL"));
+        assert_eq!(
+            key_event_from_str("alt+Up+ctrl").unwrap(),
+            key!("alt+Up+ctrl")
+        );
+    }
+    #[test]
+    #[should_panic(expected = "key!(\"CTRL+Quit\") is not a valid key event")]
+    fn should_panic_for_invalid_key_code() {
+        let _ = key!("CTRL+Quit");
+    }
+}
@@ -0,0 +1,1497 @@
+//! Conversion of key codes and key modifiers from and into their readable text.
+//!
+//! Unlike the serde modules this needs neither serde nor std but only alloc,
+//! so it is also available without the default feature "std".
+
+// Some of the helpers are only used by the serde modules.
+#![cfg_attr(not(feature = "std"), allow(dead_code))]
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::KeyParseError;
+
+/// How forgiving the parsing of a key event is on deserialize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Accepts common variations like modifiers delimited by "+", "," or whitespace.
+    #[default]
+    Lenient,
+    /// Accepts only the canonical form like modifiers delimited by "+".
+    /// NONE is only valid as the sole modifier keyword.
+    Strict,
+}
+
+/// Byte order mark which a file saved on Windows may have in front of its first value.
+const BOM: char = '\u{feff}';
+
+/// Trims a text from a config like [`str::trim`] and also strips a [`BOM`],
+/// which is no whitespace. A "\r" of a Windows line ending is whitespace, so it is trimmed too.
+pub(crate) fn trim_config_text(text: &str) -> &str {
+    text.trim_matches(|next: char| next.is_whitespace() || next == BOM)
+}
+
+pub(crate) mod key_code {
+    use alloc::borrow::Cow;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::fmt;
+    use core::ops::RangeInclusive;
+    use crossterm::event::ModifierKeyCode;
+
+    use super::*;
+
+    /// Keyword of every key code with a name, one entry per key code in a fixed order.
+    ///
+    /// Serialize does not look up this table in reverse but matches the key code
+    /// in [`key_code_to_text`], so the written keyword never depends on the iteration order
+    /// of a map. An alternative spelling belongs to [`KEY_CODE_ALIASES`] instead.
+    const KEYWORDS: &[(&str, KeyCode)] = &[
+        ("Backspace", KeyCode::Backspace),
+        ("Enter", KeyCode::Enter),
+        ("Left", KeyCode::Left),
+        ("Right", KeyCode::Right),
+        ("Up", KeyCode::Up),
+        ("Down", KeyCode::Down),
+        ("Home", KeyCode::Home),
+        ("End", KeyCode::End),
+        ("PageUp", KeyCode::PageUp),
+        ("PageDown", KeyCode::PageDown),
+        ("Tab", KeyCode::Tab),
+        ("BackTab", KeyCode::BackTab),
+        ("Delete", KeyCode::Delete),
+        ("Insert", KeyCode::Insert),
+        ("Null", KeyCode::Null),
+        ("Esc", KeyCode::Esc),
+        ("CapsLock", KeyCode::CapsLock),
+        ("ScrollLock", KeyCode::ScrollLock),
+        ("NumLock", KeyCode::NumLock),
+        ("PrintScreen", KeyCode::PrintScreen),
+        ("Pause", KeyCode::Pause),
+        ("Menu", KeyCode::Menu),
+        ("KeypadBegin", KeyCode::KeypadBegin),
+    ];
+
+    /// Alternative spellings for key codes which are accepted on deserialize in lenient mode.
+    ///
+    /// They are common in configs of other tools.
+    /// On serialize the canonical keyword like "Esc" for "Escape" is emitted.
+    pub const KEY_CODE_ALIASES: &[(&str, KeyCode)] = &[
+        ("Escape", KeyCode::Esc),
+        ("Return", KeyCode::Enter),
+        ("PgUp", KeyCode::PageUp),
+        ("PgDn", KeyCode::PageDown),
+        ("Del", KeyCode::Delete),
+    ];
+
+    /// Escape key written as its control sequence like in configs of tools which bind raw bytes.
+    /// They are only accepted in lenient mode and written as "Esc" on serialize.
+    const ESC_SEQUENCES: &[&str] = &["\\e", "\\x1b", "^["];
+
+    /// Keys of modifiers which terminals with the kitty keyboard protocol report on their own
+    /// like the left ALT key. Such a key is a key code like "LeftAlt" unlike the modifier ALT
+    /// which is pressed together with a key code. The keywords are the names of the variants.
+    const MODIFIER_KEYS: &[(&str, ModifierKeyCode)] = &[
+        ("LeftShift", ModifierKeyCode::LeftShift),
+        ("LeftControl", ModifierKeyCode::LeftControl),
+        ("LeftAlt", ModifierKeyCode::LeftAlt),
+        ("LeftSuper", ModifierKeyCode::LeftSuper),
+        ("LeftHyper", ModifierKeyCode::LeftHyper),
+        ("LeftMeta", ModifierKeyCode::LeftMeta),
+        ("RightShift", ModifierKeyCode::RightShift),
+        ("RightControl", ModifierKeyCode::RightControl),
+        ("RightAlt", ModifierKeyCode::RightAlt),
+        ("RightSuper", ModifierKeyCode::RightSuper),
+        ("RightHyper", ModifierKeyCode::RightHyper),
+        ("RightMeta", ModifierKeyCode::RightMeta),
+        ("IsoLevel3Shift", ModifierKeyCode::IsoLevel3Shift),
+        ("IsoLevel5Shift", ModifierKeyCode::IsoLevel5Shift),
+    ];
+
+    pub(crate) fn is_modifier_key(text: &str) -> bool {
+        MODIFIER_KEYS.iter().any(|(keyword, _)| *keyword == text)
+    }
+
+    /// Modifier key of one side like "LeftAlt" with the modifier it sets while it is held.
+    /// The ISO level shifts set no modifier, so they are never held in front of a key code.
+    #[cfg(feature = "std")]
+    pub(crate) fn sided_modifier_key(text: &str) -> Option<(ModifierKeyCode, KeyModifiers)> {
+        let (_, key) = MODIFIER_KEYS.iter().find(|(keyword, _)| *keyword == text)?;
+        let modifier = match key {
+            ModifierKeyCode::LeftShift | ModifierKeyCode::RightShift => KeyModifiers::SHIFT,
+            ModifierKeyCode::LeftControl | ModifierKeyCode::RightControl => KeyModifiers::CONTROL,
+            ModifierKeyCode::LeftAlt | ModifierKeyCode::RightAlt => KeyModifiers::ALT,
+            ModifierKeyCode::LeftSuper | ModifierKeyCode::RightSuper => KeyModifiers::SUPER,
+            ModifierKeyCode::LeftHyper | ModifierKeyCode::RightHyper => KeyModifiers::HYPER,
+            ModifierKeyCode::LeftMeta | ModifierKeyCode::RightMeta => KeyModifiers::META,
+            ModifierKeyCode::IsoLevel3Shift | ModifierKeyCode::IsoLevel5Shift => return None,
+        };
+        Some((*key, modifier))
+    }
+
+    /// Chars which can not be seen in a configuration file or are lost by trimming.
+    const CHAR_KEYWORDS: &[(&str, char)] = &[("Space", ' ')];
+
+    /// Control chars which stand for a key with its own key code.
+    pub(crate) const CONTROL_CHARS: &[(char, KeyCode)] = &[
+        ('\t', KeyCode::Tab),
+        ('\n', KeyCode::Enter),
+        ('\r', KeyCode::Enter),
+        ('\u{1b}', KeyCode::Esc),
+        ('\u{8}', KeyCode::Backspace),
+        ('\u{7f}', KeyCode::Backspace),
+        ('\u{0}', KeyCode::Null),
+    ];
+
+    /// Keyword of the key which is meant by a control char like "Esc" for '\u{1b}'.
+    pub(crate) fn control_char_keyword(control: char) -> Option<&'static str> {
+        CONTROL_CHARS
+            .iter()
+            .find(|(next, _)| *next == control)
+            .and_then(|(_, code)| {
+                KEYWORDS
+                    .iter()
+                    .find(|(_, next)| next == code)
+                    .map(|(keyword, _)| *keyword)
+            })
+    }
+
+    /// Every keyword for a key code except function keys.
+    pub(crate) fn keyword_names() -> impl Iterator<Item = &'static str> {
+        KEYWORDS
+            .iter()
+            .map(|(keyword, _)| *keyword)
+            .chain(KEY_CODE_ALIASES.iter().map(|(alias, _)| *alias))
+            .chain(CHAR_KEYWORDS.iter().map(|(keyword, _)| *keyword))
+            .chain(MODIFIER_KEYS.iter().map(|(keyword, _)| *keyword))
+    }
+
+    /// Key codes which can be written as text, for driving property and fuzz tests.
+    ///
+    /// Yields every key code with a keyword like [`KeyCode::Up`], every modifier key
+    /// like [`ModifierKeyCode::LeftAlt`], F1 up to F24 and the printable ASCII chars
+    /// from ' ' to '~'. It does not enumerate all chars: any other visible char
+    /// like 'あ' is representable as well.
+    pub fn iter_representable_key_codes() -> impl Iterator<Item = KeyCode> {
+        KEYWORDS
+            .iter()
+            .map(|(_, code)| *code)
+            .chain(MODIFIER_KEYS.iter().map(|(_, key)| KeyCode::Modifier(*key)))
+            .chain(FUNCTION_KEYS.map(KeyCode::F))
+            .chain((' '..='~').map(KeyCode::Char))
+    }
+
+    /// Keyword which is spelled most like the text, so a typo like "Enterr" can be pointed out.
+    ///
+    /// Returns none if no keyword is close enough to be meant by the text.
+    /// For an alias like "Escape" its canonical keyword "Esc" is suggested, which is also
+    /// accepted in strict mode.
+    pub(crate) fn closest_keyword(text: &str) -> Option<&'static str> {
+        const MAX_DISTANCE: usize = 2;
+        let text = text.to_ascii_lowercase();
+        let closest = keyword_names()
+            .map(|keyword| (edit_distance(&text, &keyword.to_ascii_lowercase()), keyword))
+            .filter(|(distance, _)| *distance <= MAX_DISTANCE)
+            .min()
+            .map(|(_, keyword)| keyword)?;
+        let canonical = KEY_CODE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == closest)
+            .and_then(|(_, code)| KEYWORDS.iter().find(|(_, next)| next == code))
+            .map(|(keyword, _)| *keyword);
+        Some(canonical.unwrap_or(closest))
+    }
+
+    /// Levenshtein distance of the chars of both texts.
+    fn edit_distance(left: &str, right: &str) -> usize {
+        let right: Vec<char> = right.chars().collect();
+        let mut previous: Vec<usize> = (0..=right.len()).collect();
+        for (row, left_char) in left.chars().enumerate() {
+            let mut current = Vec::with_capacity(previous.len());
+            current.push(row + 1);
+            for (column, right_char) in right.iter().enumerate() {
+                let substitution = previous[column] + usize::from(left_char != *right_char);
+                let insertion = current[column] + 1;
+                let deletion = previous[column + 1] + 1;
+                current.push(substitution.min(insertion).min(deletion));
+            }
+            previous = current;
+        }
+        previous[right.len()]
+    }
+
+    /// Function keys which can be written as text like F5.
+    pub(crate) const FUNCTION_KEYS: RangeInclusive<u8> = 1..=24;
+
+    /// Converts a key code into its readable text.
+    ///
+    /// A control char like '\u{1b}' would end up as an invisible byte in a config.
+    /// Therefore it is written as code point like "U+001B", which is read back as the same char.
+    /// Only a tab and a new line are written as their keyword like "Tab" since they are the same key.
+    /// Other whitespace like '\u{a0}' is written as code point like "U+00A0" too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a key code without text like a media key.
+    pub fn key_code_to_text(code: &KeyCode) -> Result<Cow<'static, str>, KeyParseError> {
+        // The match has an arm for every key code instead of a wildcard,
+        // so a key code added by crossterm fails to compile until it is handled here.
+        // Crossterm 0.27 up to 0.29 have the same key codes as 0.26, so no arm needs a feature
+        // like crossterm-0_27. An arm for a key code of a newer crossterm goes behind its feature.
+        // The keywords must be the same as in KEYWORDS.
+        match code {
+            KeyCode::Char(char) if char.is_control() => match control_char_keyword(*char) {
+                // A tab or a new line would be written as invisible whitespace.
+                Some(keyword) if char.is_whitespace() => Ok(Cow::Borrowed(keyword)),
+                _ => Ok(Cow::Owned(format!("U+{:04X}", u32::from(*char)))),
+            },
+            KeyCode::Char(char) => {
+                if let Some((keyword, _)) = CHAR_KEYWORDS.iter().find(|(_, next)| next == char) {
+                    Ok(Cow::Borrowed(keyword))
+                } else if char.is_whitespace() {
+                    Ok(Cow::Owned(format!("U+{:04X}", u32::from(*char))))
+                } else {
+                    Ok(Cow::Owned(char.to_string()))
+                }
+            }
+            KeyCode::F(number) if FUNCTION_KEYS.contains(number) => {
+                Ok(Cow::Owned(format!("F{}", number)))
+            }
+            KeyCode::F(number) => Err(KeyParseError::OutOfRangeFunctionKey(*number)),
+            KeyCode::Modifier(modifier_key) => MODIFIER_KEYS
+                .iter()
+                .find(|(_, next)| next == modifier_key)
+                .map(|(keyword, _)| Cow::Borrowed(*keyword))
+                .ok_or(KeyParseError::UnsupportedKeyCode(*code)),
+            KeyCode::Media(_) => Err(KeyParseError::UnsupportedKeyCode(*code)),
+            KeyCode::Backspace => Ok(Cow::Borrowed("Backspace")),
+            KeyCode::Enter => Ok(Cow::Borrowed("Enter")),
+            KeyCode::Left => Ok(Cow::Borrowed("Left")),
+            KeyCode::Right => Ok(Cow::Borrowed("Right")),
+            KeyCode::Up => Ok(Cow::Borrowed("Up")),
+            KeyCode::Down => Ok(Cow::Borrowed("Down")),
+            KeyCode::Home => Ok(Cow::Borrowed("Home")),
+            KeyCode::End => Ok(Cow::Borrowed("End")),
+            KeyCode::PageUp => Ok(Cow::Borrowed("PageUp")),
+            KeyCode::PageDown => Ok(Cow::Borrowed("PageDown")),
+            KeyCode::Tab => Ok(Cow::Borrowed("Tab")),
+            KeyCode::BackTab => Ok(Cow::Borrowed("BackTab")),
+            KeyCode::Delete => Ok(Cow::Borrowed("Delete")),
+            KeyCode::Insert => Ok(Cow::Borrowed("Insert")),
+            KeyCode::Null => Ok(Cow::Borrowed("Null")),
+            KeyCode::Esc => Ok(Cow::Borrowed("Esc")),
+            KeyCode::CapsLock => Ok(Cow::Borrowed("CapsLock")),
+            KeyCode::ScrollLock => Ok(Cow::Borrowed("ScrollLock")),
+            KeyCode::NumLock => Ok(Cow::Borrowed("NumLock")),
+            KeyCode::PrintScreen => Ok(Cow::Borrowed("PrintScreen")),
+            KeyCode::Pause => Ok(Cow::Borrowed("Pause")),
+            KeyCode::Menu => Ok(Cow::Borrowed("Menu")),
+            KeyCode::KeypadBegin => Ok(Cow::Borrowed("KeypadBegin")),
+        }
+    }
+
+    /// Parses the text of a key code in this order:
+    ///
+    /// 1. A single char like "5", "F" or "/" is always that char.
+    /// 2. A keyword like "Up", then an alias like "Escape" only in lenient mode,
+    ///    then a char keyword like "Space", then a modifier key like "LeftAlt".
+    /// 3. A function key like "F5".
+    /// 4. A code point like "U+001B".
+    ///
+    /// Since single chars come first, a growing set of keywords can never shadow a char.
+    /// A keyword or alias of a single char would never be reached, so every name has several chars.
+    ///
+    /// With the feature "fullwidth" a fullwidth char like 'Ａ' is read as its ASCII char like 'A'.
+    /// With the feature "nfkc" a char is read as the one char of its Unicode normalization NFKC
+    /// like 'A' for 'Ａ' or 'カ' for 'ｶ'.
+    ///
+    /// A text in a matching pair of quotes like "'a'" is read without them in lenient mode.
+    /// Such quotes are typed by mistake in formats which already quote the text like TOML.
+    /// The escape key may be written as its control sequence like "\\e" or "^[" in lenient mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crossterm::event::KeyCode;
+    /// use crossterm_serde::parse_key_code;
+    ///
+    /// assert_eq!(Ok(KeyCode::PageUp), parse_key_code("PgUp"));
+    /// assert_eq!(Ok(KeyCode::Char('A')), parse_key_code("U+0041"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is neither one char nor a known keyword.
+    pub fn parse_key_code(text: &str) -> Result<KeyCode, KeyParseError> {
+        parse_key_code_with(text, ParseMode::Lenient)
+    }
+
+    pub(crate) fn parse_key_code_with(
+        text: &str,
+        mode: ParseMode,
+    ) -> Result<KeyCode, KeyParseError> {
+        match (strip_quotes(text), mode) {
+            (Some(unquoted), ParseMode::Lenient) => parse_lenient_key_code(unquoted),
+            (Some(_), ParseMode::Strict) => Err(KeyParseError::QuotedKeyCode(text.to_string())),
+            (None, ParseMode::Lenient) => parse_lenient_key_code(text),
+            (None, ParseMode::Strict) => parse_strict_key_code(text),
+        }
+    }
+
+    /// A single control char like a literal tab is invisible in a config.
+    /// Lenient mode reads it for older configs, strict mode asks for its keyword or code point.
+    fn parse_strict_key_code(text: &str) -> Result<KeyCode, KeyParseError> {
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(char), None) if char.is_control() => Err(KeyParseError::InvalidChar(char)),
+            _ => parse_unquoted_key_code(text),
+        }
+    }
+
+    /// Aliases have several chars and no keyword is an alias, so checking them first
+    /// shadows neither a single char nor a keyword.
+    fn parse_lenient_key_code(text: &str) -> Result<KeyCode, KeyParseError> {
+        if ESC_SEQUENCES.contains(&text) {
+            Ok(KeyCode::Esc)
+        } else if let Some((_, alias)) = KEY_CODE_ALIASES.iter().find(|(next, _)| *next == text) {
+            Ok(*alias)
+        } else {
+            parse_unquoted_key_code(text)
+        }
+    }
+
+    /// Key code of a text which is no built-in keyword like a pseudo key of a plugin.
+    pub(crate) type KeywordFallback = fn(&str) -> Option<KeyCode>;
+
+    /// Same as [`parse_key_code_with`] but asks the fallback for a text which would be
+    /// an unknown keyword. Any other error like for a modifier keyword stays.
+    pub(crate) fn parse_key_code_with_fallback(
+        text: &str,
+        mode: ParseMode,
+        fallback: Option<KeywordFallback>,
+    ) -> Result<KeyCode, KeyParseError> {
+        match (parse_key_code_with(text, mode), fallback) {
+            (Err(KeyParseError::UnknownKeyword(token)), Some(fallback)) => {
+                fallback(&token).ok_or(KeyParseError::UnknownKeyword(token))
+            }
+            (result, _) => result,
+        }
+    }
+
+    /// Spelling of a key code which is accepted but is not the canonical keyword.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct AliasUsed {
+        /// Alias as written like "Return".
+        pub alias: String,
+        /// Keyword which is written on serialize like "Enter".
+        pub canonical: &'static str,
+    }
+
+    impl fmt::Display for AliasUsed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "'{}' is an alias, '{}' is the canonical spelling",
+                self.alias, self.canonical
+            )
+        }
+    }
+
+    /// Parses the text of a key code like deserialize and tells if an alias like "Return"
+    /// was used instead of the canonical keyword like "Enter".
+    ///
+    /// This allows a linter to point out spellings which work but could be tidied up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crossterm::event::KeyCode;
+    /// use crossterm_serde::parse_key_code_with_diagnostics;
+    ///
+    /// let (code, alias) = parse_key_code_with_diagnostics("Return").unwrap();
+    /// assert_eq!(KeyCode::Enter, code);
+    /// assert_eq!("Enter", alias.unwrap().canonical);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not a valid key code.
+    pub fn parse_key_code_with_diagnostics(
+        text: &str,
+    ) -> Result<(KeyCode, Option<AliasUsed>), KeyParseError> {
+        let text = trim_config_text(text);
+        let code = parse_key_code(text)?;
+        let unquoted = strip_quotes(text).unwrap_or(text);
+        let is_alias = KEY_CODE_ALIASES.iter().any(|(alias, _)| *alias == unquoted)
+            || ESC_SEQUENCES.contains(&unquoted);
+        let alias = is_alias
+            .then(|| KEYWORDS.iter().find(|(_, next)| *next == code))
+            .flatten()
+            .map(|(canonical, _)| AliasUsed {
+                alias: unquoted.to_string(),
+                canonical,
+            });
+        Ok((code, alias))
+    }
+
+    /// Text within a matching pair of single or double quotes like "a" for "'a'".
+    /// A single quote char like "'" is a key code on its own and is not stripped.
+    fn strip_quotes(text: &str) -> Option<&str> {
+        ['\'', '"'].into_iter().find_map(|quote| {
+            text.strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+                .filter(|unquoted| !unquoted.is_empty())
+        })
+    }
+
+    fn parse_unquoted_key_code(text: &str) -> Result<KeyCode, KeyParseError> {
+        if text.is_empty() {
+            Err(KeyParseError::EmptyInput)
+        } else if text.chars().count() == 1 {
+            let char = text.chars().next().unwrap();
+            #[cfg(feature = "fullwidth")]
+            let char = fullwidth_to_ascii(char);
+            #[cfg(feature = "nfkc")]
+            let char = nfkc_char(char);
+            Ok(KeyCode::Char(char))
+        } else if let Some((_, valid_keyword)) = KEYWORDS.iter().find(|(next, _)| *next == text) {
+            Ok(*valid_keyword)
+        } else if let Some((_, char)) = CHAR_KEYWORDS.iter().find(|(next, _)| *next == text) {
+            Ok(KeyCode::Char(*char))
+        } else if let Some((_, key)) = MODIFIER_KEYS.iter().find(|(next, _)| *next == text) {
+            Ok(KeyCode::Modifier(*key))
+        } else if let Some(function_key) = parse_function_key(text) {
+            function_key
+        } else if let Some(code_point) = parse_code_point(text) {
+            code_point
+        } else if key_modifier::is_keyword(text) {
+            Err(KeyParseError::ModifierAsKeyCode(text.to_string()))
+        } else if is_grapheme_cluster(text) {
+            Err(KeyParseError::GraphemeCluster(text.to_string()))
+        } else {
+            Err(KeyParseError::UnknownKeyword(text.to_string()))
+        }
+    }
+
+    /// Char after NFKC like 'A' for 'Ａ'. A char like 'ﬁ' which becomes several chars is kept.
+    #[cfg(feature = "nfkc")]
+    fn nfkc_char(char: char) -> char {
+        use unicode_normalization::UnicodeNormalization;
+        let mut normalized = core::iter::once(char).nfkc();
+        match (normalized.next(), normalized.next()) {
+            (Some(normalized), None) => normalized,
+            _ => char,
+        }
+    }
+
+    /// Fullwidth forms of ASCII chars from U+FF01 to U+FF5E and the ideographic space as in
+    /// the compatibility decomposition of NFKC. They are typed by an input method for CJK
+    /// and look like the ASCII chars, but a terminal reports the ASCII char for the key.
+    #[cfg(feature = "fullwidth")]
+    fn fullwidth_to_ascii(char: char) -> char {
+        const FULLWIDTH: RangeInclusive<u32> = 0xFF01..=0xFF5E;
+        const OFFSET: u32 = 0xFF01 - 0x21;
+        let code = u32::from(char);
+        if FULLWIDTH.contains(&code) {
+            char::from_u32(code - OFFSET).unwrap_or(char)
+        } else if char == '\u{3000}' {
+            ' '
+        } else {
+            char
+        }
+    }
+
+    /// Returns none if the text does not have the shape of a function key like F5.
+    fn parse_function_key(text: &str) -> Option<Result<KeyCode, KeyParseError>> {
+        let digits = text.strip_prefix('F')?;
+        if digits.is_empty() || !digits.chars().all(|next| next.is_ascii_digit()) {
+            return None;
+        }
+        let number: u8 = digits.parse().ok()?;
+        if FUNCTION_KEYS.contains(&number) {
+            Some(Ok(KeyCode::F(number)))
+        } else {
+            Some(Err(KeyParseError::OutOfRangeFunctionKey(number)))
+        }
+    }
+
+    /// Returns true if the text looks like one visible char made of several chars
+    /// like the emoji "👩‍💻" joined by a zero width joiner or the flag "🇩🇪".
+    ///
+    /// Without a table of the unicode grapheme rules only the common extending chars are known.
+    fn is_grapheme_cluster(text: &str) -> bool {
+        const ZERO_WIDTH_JOINER: char = '\u{200d}';
+        fn is_extending(next: char) -> bool {
+            matches!(
+                next,
+                ZERO_WIDTH_JOINER
+                    // Combining diacritical marks like the accent of "é" as 'e' and '\u{301}'.
+                    | '\u{300}'..='\u{36f}'
+                    // Combining marks for symbols like the keycap of "1️⃣".
+                    | '\u{20d0}'..='\u{20ff}'
+                    // Variation selectors like the emoji style of "❤️".
+                    | '\u{fe00}'..='\u{fe0f}'
+                    // Skin tones like in "👍🏽".
+                    | '\u{1f3fb}'..='\u{1f3ff}'
+                    // Tags like in the flag of Scotland.
+                    | '\u{e0020}'..='\u{e007f}'
+            )
+        }
+        fn is_regional_indicator(next: char) -> bool {
+            ('\u{1f1e6}'..='\u{1f1ff}').contains(&next)
+        }
+
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() < 2 {
+            return false;
+        }
+        if chars.len() == 2 && chars.iter().all(|next| is_regional_indicator(*next)) {
+            return true;
+        }
+        chars
+            .windows(2)
+            .all(|pair| is_extending(pair[1]) || pair[0] == ZERO_WIDTH_JOINER)
+    }
+
+    /// Returns none if the text does not have the shape of a code point like "U+001B" or
+    /// "\u{1b}". This allows to write chars which can not be typed.
+    fn parse_code_point(text: &str) -> Option<Result<KeyCode, KeyParseError>> {
+        let digits = text.strip_prefix("U+").or_else(|| {
+            text.strip_prefix("\\u{")
+                .and_then(|rest| rest.strip_suffix('}'))
+        })?;
+        let invalid = || KeyParseError::InvalidCodePoint(text.to_string());
+        if digits.is_empty()
+            || digits.len() > 6
+            || !digits.chars().all(|next| next.is_ascii_hexdigit())
+        {
+            return Some(Err(invalid()));
+        }
+        let char = u32::from_str_radix(digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(invalid);
+        Some(char.map(KeyCode::Char))
+    }
+
+    #[cfg(test)]
+    mod testing {
+        use super::*;
+
+        #[test]
+        fn should_iter_representable_key_codes() {
+            let codes: Vec<KeyCode> = iter_representable_key_codes().collect();
+            for (keyword, code) in KEYWORDS {
+                assert!(codes.contains(code), "{} is missing", keyword);
+            }
+            for number in 1..=24 {
+                assert!(
+                    codes.contains(&KeyCode::F(number)),
+                    "F{} is missing",
+                    number
+                );
+            }
+            assert!(!codes.contains(&KeyCode::F(25)));
+            assert!(codes.contains(&KeyCode::Char(' ')));
+            assert!(codes.contains(&KeyCode::Char('~')));
+            for code in codes {
+                let text = key_code_to_text(&code).unwrap();
+                assert_eq!(
+                    code,
+                    parse_key_code(&text).unwrap(),
+                    "{} should round trip",
+                    text
+                );
+            }
+        }
+        #[test]
+        fn should_parse_code_point() {
+            assert_eq!(KeyCode::Char('A'), parse_key_code("U+0041").unwrap());
+            assert_eq!(KeyCode::Char('\u{1b}'), parse_key_code("U+001B").unwrap());
+            assert_eq!(KeyCode::Char('\u{1b}'), parse_key_code("\\u{1b}").unwrap());
+            assert_eq!(KeyCode::Char('あ'), parse_key_code("\\u{3042}").unwrap());
+            for invalid in ["U+", "U+D800", "U+110000", "U+12G4", "U+0000041", "\\u{}"] {
+                assert_eq!(
+                    KeyParseError::InvalidCodePoint(invalid.to_string()),
+                    parse_key_code(invalid).unwrap_err(),
+                    "{} should be invalid",
+                    invalid
+                );
+            }
+        }
+        #[test]
+        fn should_produce_text_as_key_code() {
+            assert_case(KeyCode::Char('a'), "a");
+            assert_case(KeyCode::Char('A'), "A");
+            assert_case(KeyCode::Char('/'), "/");
+            assert_case(KeyCode::Up, "Up");
+            assert_case(KeyCode::Enter, "Enter");
+            assert_case(KeyCode::F(1), "F1");
+            assert_case(KeyCode::F(24), "F24");
+            assert_case(KeyCode::Char(' '), "Space");
+            fn assert_case(input: KeyCode, expected: &str) {
+                let actual = key_code_to_text(&input).unwrap();
+                assert_eq!(expected, &actual);
+            }
+        }
+        #[test]
+        fn should_parse_valid_key_code() {
+            assert_case("a", KeyCode::Char('a'));
+            assert_case("A", KeyCode::Char('A'));
+            assert_case("/", KeyCode::Char('/'));
+            assert_case("Up", KeyCode::Up);
+            assert_case("Enter", KeyCode::Enter);
+            assert_case("F1", KeyCode::F(1));
+            assert_case("F12", KeyCode::F(12));
+            assert_case("Space", KeyCode::Char(' '));
+            fn assert_case(input: &str, expected: KeyCode) {
+                let actual = parse_key_code(input).unwrap();
+                assert_eq!(expected, actual);
+            }
+        }
+        macro_rules! named_key_codes {
+            ($($variant:ident),* $(,)?) => {
+                // Exhaustive on purpose, a new variant in crossterm must break the build here.
+                fn variant_name(code: &KeyCode) -> Option<&'static str> {
+                    match code {
+                        $(KeyCode::$variant => Some(stringify!($variant)),)*
+                        KeyCode::F(_)
+                        | KeyCode::Char(_)
+                        | KeyCode::Media(_)
+                        | KeyCode::Modifier(_) => None,
+                    }
+                }
+                const NAMED_KEY_CODES: &[KeyCode] = &[$(KeyCode::$variant),*];
+            };
+        }
+        named_key_codes!(
+            Backspace,
+            Enter,
+            Left,
+            Right,
+            Up,
+            Down,
+            Home,
+            End,
+            PageUp,
+            PageDown,
+            Tab,
+            BackTab,
+            Delete,
+            Insert,
+            Null,
+            Esc,
+            CapsLock,
+            ScrollLock,
+            NumLock,
+            PrintScreen,
+            Pause,
+            Menu,
+            KeypadBegin,
+        );
+        #[test]
+        fn should_name_keywords_like_crossterm_variants() {
+            assert_eq!(NAMED_KEY_CODES.len(), KEYWORDS.len());
+            for input in NAMED_KEY_CODES {
+                let expected = variant_name(input).unwrap();
+                let actual = key_code_to_text(input).unwrap();
+                assert_eq!(expected, actual);
+            }
+        }
+        #[test]
+        fn should_always_serialize_same_keyword() {
+            for (index, (keyword, code)) in KEYWORDS.iter().enumerate() {
+                assert!(
+                    KEYWORDS[index + 1..].iter().all(|(_, next)| next != code),
+                    "{:?} has more than one keyword",
+                    code
+                );
+                for _ in 0..3 {
+                    assert_eq!(*keyword, key_code_to_text(code).unwrap());
+                }
+            }
+            for (_, code) in KEY_CODE_ALIASES {
+                let canonical = KEYWORDS.iter().find(|(_, next)| next == code).unwrap().0;
+                assert_eq!(canonical, key_code_to_text(code).unwrap());
+            }
+            assert_eq!("Esc", control_char_keyword('\u{1b}').unwrap());
+            assert_eq!("Enter", control_char_keyword('\r').unwrap());
+        }
+        #[test]
+        fn should_round_trip_every_key_code() {
+            let keywords = KEYWORDS.iter().map(|(_, code)| *code);
+            let modifier_keys = MODIFIER_KEYS.iter().map(|(_, key)| KeyCode::Modifier(*key));
+            let function_keys = FUNCTION_KEYS.map(KeyCode::F);
+            let chars = ('!'..='~').chain(['é', 'ß', 'ж', 'あ']).map(KeyCode::Char);
+            for input in keywords
+                .chain(modifier_keys)
+                .chain(function_keys)
+                .chain(chars)
+            {
+                let text = key_code_to_text(&input).unwrap();
+                let actual = parse_key_code(&text).unwrap();
+                assert_eq!(input, actual, "Text {} did not round trip", text);
+            }
+        }
+        #[cfg(feature = "fullwidth")]
+        #[test]
+        fn should_read_fullwidth_chars_as_ascii() {
+            assert_case("Ａ", 'A');
+            assert_case("ａ", 'a');
+            assert_case("１", '1');
+            assert_case("！", '!');
+            assert_case("～", '~');
+            assert_case("\u{3000}", ' ');
+            assert_case("あ", 'あ');
+            fn assert_case(input: &str, expected: char) {
+                assert_eq!(KeyCode::Char(expected), parse_key_code(input).unwrap());
+            }
+        }
+        #[cfg(feature = "nfkc")]
+        #[test]
+        fn should_read_nfkc_of_key_code() {
+            assert_case("Ａ", 'A');
+            assert_case("ｶ", 'カ');
+            assert_case("①", '1');
+            assert_case("ﬁ", 'ﬁ');
+            assert_case("a", 'a');
+            fn assert_case(input: &str, expected: char) {
+                assert_eq!(KeyCode::Char(expected), parse_key_code(input).unwrap());
+            }
+            assert_eq!(KeyCode::Up, parse_key_code("Up").unwrap());
+        }
+        #[test]
+        fn should_prefer_every_single_char_over_names() {
+            for name in keyword_names() {
+                assert!(
+                    name.chars().count() > 1,
+                    "Name {} would be shadowed by its char",
+                    name
+                );
+            }
+            let chars = ('!'..='~').chain(['é', 'ß', 'あ']);
+            for input in chars {
+                let actual = parse_key_code(&input.to_string()).unwrap();
+                assert_eq!(KeyCode::Char(input), actual);
+            }
+        }
+        #[test]
+        fn should_suggest_closest_keyword() {
+            assert_case(
+                "Enterr",
+                "'Enterr' is not a recognized key name, did you mean 'Enter'?",
+            );
+            assert_case(
+                "pgup",
+                "'pgup' is not a recognized key name, did you mean 'PageUp'?",
+            );
+            assert_case(
+                "Escpae",
+                "'Escpae' is not a recognized key name, did you mean 'Esc'?",
+            );
+            assert_case("Banana", "'Banana' is not a recognized key name");
+            fn assert_case(input: &str, message: &str) {
+                let actual = parse_key_code(input).unwrap_err();
+                assert_eq!(KeyParseError::UnknownKeyword(input.to_string()), actual);
+                assert_eq!(message, actual.to_string());
+            }
+        }
+        #[test]
+        fn should_strip_quotes_in_lenient_mode() {
+            assert_case("'a'", KeyCode::Char('a'));
+            assert_case("\"a\"", KeyCode::Char('a'));
+            assert_case("'Up'", KeyCode::Up);
+            assert_case("'''", KeyCode::Char('\''));
+            assert_case("'", KeyCode::Char('\''));
+            assert_case("\"", KeyCode::Char('"'));
+            fn assert_case(input: &str, expected: KeyCode) {
+                assert_eq!(Ok(expected), parse_key_code_with(input, ParseMode::Lenient));
+            }
+            assert!(parse_key_code_with("'a\"", ParseMode::Lenient).is_err());
+            assert!(parse_key_code_with("''", ParseMode::Lenient).is_err());
+        }
+        #[test]
+        fn should_deny_quotes_in_strict_mode() {
+            let actual = parse_key_code_with("'a'", ParseMode::Strict);
+            assert_eq!(Err(KeyParseError::QuotedKeyCode("'a'".to_string())), actual);
+            assert_eq!(
+                "'a' has extra quotes around the key code, write it without them",
+                actual.unwrap_err().to_string()
+            );
+            assert_eq!(
+                Ok(KeyCode::Char('\'')),
+                parse_key_code_with("'", ParseMode::Strict)
+            );
+        }
+        #[test]
+        fn should_deny_single_control_char_in_strict_mode() {
+            assert_case(
+                '\t',
+                "'\\t' is an invisible control char, write Tab instead",
+            );
+            assert_case(
+                '\u{1}',
+                "'\\u{1}' is an invisible control char, write U+0001 instead",
+            );
+            assert_eq!(
+                Ok(KeyCode::Char('\u{1}')),
+                parse_key_code_with("\u{1}", ParseMode::Lenient)
+            );
+            fn assert_case(input: char, message: &str) {
+                let actual = parse_key_code_with(&input.to_string(), ParseMode::Strict);
+                assert_eq!(Err(KeyParseError::InvalidChar(input)), actual);
+                assert_eq!(message, actual.unwrap_err().to_string());
+            }
+        }
+        #[test]
+        fn should_parse_esc_sequences_in_lenient_mode() {
+            for sequence in ["\\e", "\\x1b", "^[", "'^['"] {
+                assert_eq!(
+                    Ok(KeyCode::Esc),
+                    parse_key_code_with(sequence, ParseMode::Lenient),
+                    "{}",
+                    sequence
+                );
+                let (_, used) = parse_key_code_with_diagnostics(sequence).unwrap();
+                assert_eq!("Esc", used.unwrap().canonical);
+            }
+            for sequence in ESC_SEQUENCES {
+                assert_eq!(
+                    Err(KeyParseError::UnknownKeyword(sequence.to_string())),
+                    parse_key_code_with(sequence, ParseMode::Strict)
+                );
+            }
+            assert_eq!("Esc", key_code_to_text(&KeyCode::Esc).unwrap());
+        }
+        #[test]
+        fn should_report_used_alias() {
+            let (code, alias) = parse_key_code_with_diagnostics("Return").unwrap();
+            assert_eq!(KeyCode::Enter, code);
+            let alias = alias.unwrap();
+            assert_eq!(
+                AliasUsed {
+                    alias: "Return".to_string(),
+                    canonical: "Enter"
+                },
+                alias
+            );
+            assert_eq!(
+                "'Return' is an alias, 'Enter' is the canonical spelling",
+                alias.to_string()
+            );
+            for (alias, code) in KEY_CODE_ALIASES {
+                let (parsed, used) = parse_key_code_with_diagnostics(alias).unwrap();
+                assert_eq!(*code, parsed);
+                assert_eq!(key_code_to_text(code).unwrap(), used.unwrap().canonical);
+            }
+            assert_eq!(
+                Ok((KeyCode::Enter, None)),
+                parse_key_code_with_diagnostics("Enter")
+            );
+            assert_eq!(
+                Ok((KeyCode::Char('a'), None)),
+                parse_key_code_with_diagnostics("a")
+            );
+            assert!(parse_key_code_with_diagnostics("Retrun").is_err());
+        }
+        #[test]
+        fn should_deny_aliases_in_strict_mode() {
+            for (alias, code) in KEY_CODE_ALIASES {
+                assert_eq!(
+                    Err(KeyParseError::UnknownKeyword(alias.to_string())),
+                    parse_key_code_with(alias, ParseMode::Strict)
+                );
+                assert_eq!(Ok(*code), parse_key_code_with(alias, ParseMode::Lenient));
+            }
+            assert_eq!(
+                "'Escape' is not a recognized key name, did you mean 'Esc'?",
+                parse_key_code_with("Escape", ParseMode::Strict)
+                    .unwrap_err()
+                    .to_string()
+            );
+        }
+        #[test]
+        fn should_deny_grapheme_clusters() {
+            assert_case("👩\u{200d}💻");
+            assert_case("👨\u{200d}👩\u{200d}👧");
+            assert_case("🇩🇪");
+            assert_case("👍🏽");
+            assert_case("e\u{301}");
+            assert_case("❤\u{fe0f}");
+            fn assert_case(input: &str) {
+                let actual = parse_key_code(input).unwrap_err();
+                assert_eq!(KeyParseError::GraphemeCluster(input.to_string()), actual);
+            }
+            assert_eq!(
+                "key codes must be a single Unicode scalar value; \
+                 '👩\u{200d}💻' is a multi-codepoint cluster",
+                parse_key_code("👩\u{200d}💻").unwrap_err().to_string()
+            );
+            assert_eq!(
+                Err(KeyParseError::UnknownKeyword("ab".to_string())),
+                parse_key_code("ab")
+            );
+            assert_eq!(Ok(KeyCode::Char('👍')), parse_key_code("👍"));
+        }
+        #[test]
+        fn should_deny_media_keys_on_serialize() {
+            let input = KeyCode::Media(crossterm::event::MediaKeyCode::Play);
+            assert_eq!(
+                Err(KeyParseError::UnsupportedKeyCode(input)),
+                key_code_to_text(&input)
+            );
+        }
+        #[test]
+        fn should_accept_aliases_and_emit_canonical_keyword() {
+            assert_case("Escape", "Esc");
+            assert_case("Return", "Enter");
+            assert_case("PgUp", "PageUp");
+            assert_case("PgDn", "PageDown");
+            assert_case("Del", "Delete");
+            fn assert_case(alias: &str, canonical: &str) {
+                let parsed = parse_key_code(alias).unwrap();
+                assert_eq!(parse_key_code(canonical).unwrap(), parsed);
+                assert_eq!(canonical, key_code_to_text(&parsed).unwrap());
+            }
+        }
+        #[test]
+        fn should_round_trip_non_ascii_char() {
+            assert_case('é');
+            assert_case('ä');
+            assert_case('ß');
+            assert_case('ж');
+            assert_case('あ');
+            fn assert_case(input: char) {
+                let text = key_code_to_text(&KeyCode::Char(input)).unwrap();
+                let actual = parse_key_code(&text).unwrap();
+                assert_eq!(KeyCode::Char(input), actual);
+            }
+        }
+        #[test]
+        fn should_point_to_modifiers_field_for_modifier_as_key_code() {
+            assert_case("ALT");
+            assert_case("CONTROL");
+            fn assert_case(input: &str) {
+                let actual = parse_key_code(input).unwrap_err();
+                let expected = format!(
+                    "'{}' is a modifier, not a key code; put it in the modifiers field",
+                    input
+                );
+                assert_eq!(expected, actual.to_string());
+            }
+        }
+        #[test]
+        fn should_report_reason_for_invalid_key_code() {
+            assert_case("", KeyParseError::EmptyInput);
+            assert_case("Upp", KeyParseError::UnknownKeyword("Upp".to_string()));
+            assert_case("ALT", KeyParseError::ModifierAsKeyCode("ALT".to_string()));
+            assert_case("F0", KeyParseError::OutOfRangeFunctionKey(0));
+            assert_case("F25", KeyParseError::OutOfRangeFunctionKey(25));
+            fn assert_case(input: &str, expected: KeyParseError) {
+                let actual = parse_key_code(input).unwrap_err();
+                assert_eq!(expected, actual);
+            }
+        }
+        #[test]
+        fn should_parse_left_and_right_modifier_keys() {
+            let left = parse_key_code("LeftAlt").unwrap();
+            let right = parse_key_code("RightAlt").unwrap();
+            assert_eq!(KeyCode::Modifier(ModifierKeyCode::LeftAlt), left);
+            assert_eq!(KeyCode::Modifier(ModifierKeyCode::RightAlt), right);
+            assert_ne!(left, right);
+            assert_eq!("LeftAlt", key_code_to_text(&left).unwrap());
+        }
+        #[test]
+        fn should_prefer_single_char_over_function_key() {
+            assert_eq!(KeyCode::Char('5'), parse_key_code("5").unwrap());
+            assert_eq!(KeyCode::F(5), parse_key_code("F5").unwrap());
+            assert_eq!(KeyCode::Char('F'), parse_key_code("F").unwrap());
+            assert_eq!(
+                KeyParseError::UnknownKeyword("Fx".to_string()),
+                parse_key_code("Fx").unwrap_err()
+            );
+            assert_eq!(
+                KeyParseError::UnknownKeyword("F+5".to_string()),
+                parse_key_code("F+5").unwrap_err()
+            );
+        }
+        #[test]
+        fn should_write_control_chars_as_code_point() {
+            assert_case('\u{1b}', "U+001B");
+            assert_case('\u{c}', "U+000C");
+            assert_case('\u{0}', "U+0000");
+            assert_case('\u{2}', "U+0002");
+            assert_case('\u{85}', "U+0085");
+            fn assert_case(input: char, expected: &str) {
+                let actual = key_code_to_text(&KeyCode::Char(input)).unwrap();
+                assert_eq!(expected, actual);
+                assert_eq!(KeyCode::Char(input), parse_key_code(&actual).unwrap());
+            }
+        }
+        #[test]
+        fn should_write_parsed_code_point_of_control_char_back() {
+            let parsed = parse_key_code("U+001B").unwrap();
+            assert_eq!(KeyCode::Char('\u{1b}'), parsed);
+            assert_eq!("U+001B", key_code_to_text(&parsed).unwrap());
+        }
+        #[test]
+        fn should_write_no_literal_whitespace() {
+            assert_case('\t', "Tab", KeyCode::Tab);
+            assert_case('\n', "Enter", KeyCode::Enter);
+            assert_case('\r', "Enter", KeyCode::Enter);
+            assert_case(' ', "Space", KeyCode::Char(' '));
+            assert_case('\u{a0}', "U+00A0", KeyCode::Char('\u{a0}'));
+            assert_case('\u{3000}', "U+3000", KeyCode::Char('\u{3000}'));
+            fn assert_case(input: char, expected: &str, parsed: KeyCode) {
+                let actual = key_code_to_text(&KeyCode::Char(input)).unwrap();
+                assert_eq!(expected, actual);
+                assert!(!actual.contains(char::is_whitespace));
+                assert_eq!(parsed, parse_key_code(&actual).unwrap());
+            }
+        }
+    }
+}
+
+pub(crate) mod key_modifier {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub(crate) const SEPERATOR: &str = "+";
+    pub(crate) const NONE: &str = "NONE";
+
+    const SHIFT: &str = "SHIFT";
+    const CONTROL: &str = "CONTROL";
+    const CTRL: &str = "CTRL";
+    const SUPER: &str = "SUPER";
+    const ALT: &str = "ALT";
+    const HYPER: &str = "HYPER";
+    const META: &str = "META";
+    const COMMAND: &str = "COMMAND";
+    const OPTION: &str = "OPTION";
+    const WIN: &str = "WIN";
+
+    /// Every keyword of [`KEYWORD`] usable in const contexts like the `key!` macro.
+    pub(crate) const KEYWORD_NAMES: [&str; 11] = [
+        SHIFT, CONTROL, CTRL, ALT, SUPER, HYPER, META, COMMAND, OPTION, WIN, NONE,
+    ];
+
+    /// Bits of every keyword, looked up without case by [`parse_key_modifier`].
+    const KEYWORD: &[(&str, KeyModifiers)] = &[
+        (SHIFT, KeyModifiers::SHIFT),
+        (CONTROL, KeyModifiers::CONTROL),
+        (CTRL, KeyModifiers::CONTROL),
+        (ALT, KeyModifiers::ALT),
+        (SUPER, KeyModifiers::SUPER),
+        (HYPER, KeyModifiers::HYPER),
+        (META, KeyModifiers::META),
+        (COMMAND, KeyModifiers::SUPER),
+        (OPTION, KeyModifiers::ALT),
+        (WIN, KeyModifiers::SUPER),
+        (NONE, KeyModifiers::NONE),
+    ];
+
+    /// Keywords of the modifiers in the order they are written on serialize.
+    ///
+    /// This canonical order is the order of the bits declared by crossterm for [`KeyModifiers`],
+    /// so the output is the same as from other tools which follow crossterm.
+    /// A UI can use it to show modifiers in the same order as a config.
+    pub const CANONICAL_MODIFIER_ORDER: &[(&str, KeyModifiers)] = &[
+        (SHIFT, KeyModifiers::SHIFT),
+        (CONTROL, KeyModifiers::CONTROL),
+        (ALT, KeyModifiers::ALT),
+        (SUPER, KeyModifiers::SUPER),
+        (HYPER, KeyModifiers::HYPER),
+        (META, KeyModifiers::META),
+    ];
+
+    /// Tokens for single bits without a keyword, which a newer crossterm might set.
+    /// They are written as hex like "0x40", so no bit is lost on serialize.
+    const BIT_TOKENS: [&str; 8] = [
+        "0x01", "0x02", "0x04", "0x08", "0x10", "0x20", "0x40", "0x80",
+    ];
+    const HEX_PREFIX: &str = "0x";
+
+    pub(crate) fn keywords(modif: &KeyModifiers) -> impl Iterator<Item = &'static str> {
+        bit_keywords(modif.bits())
+    }
+
+    /// Keywords of raw bits, which may have bits without a flag of crossterm.
+    fn bit_keywords(bits: u8) -> impl Iterator<Item = &'static str> {
+        let none = (bits == 0).then_some(NONE);
+        let unknown = bits & !KeyModifiers::all().bits();
+        let unknown_bits = BIT_TOKENS
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| unknown & (1 << index) != 0)
+            .map(|(_, token)| *token);
+        CANONICAL_MODIFIER_ORDER
+            .iter()
+            .filter(move |(_, flag)| bits & flag.bits() == flag.bits())
+            .map(|(keyword, _)| *keyword)
+            .chain(unknown_bits)
+            .chain(none)
+    }
+
+    /// Keywords of the modifiers in the [`CANONICAL_MODIFIER_ORDER`] like ["CONTROL", "ALT"].
+    ///
+    /// No modifiers are ["NONE"]. Bits without a keyword are tokens like "0x40".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crossterm::event::KeyModifiers;
+    /// use crossterm_serde::bits_to_strs;
+    ///
+    /// let modifiers = KeyModifiers::ALT | KeyModifiers::CONTROL;
+    /// assert_eq!(vec!["CONTROL", "ALT"], bits_to_strs(&modifiers));
+    /// ```
+    pub fn bits_to_strs(modif: &KeyModifiers) -> Vec<&'static str> {
+        keywords(modif).collect()
+    }
+
+    /// Delimiters between modifier keywords in lenient mode besides any whitespace.
+    /// In strict mode only the [`SEPERATOR`] is a delimiter.
+    pub(crate) const LENIENT_DELIMITERS: [char; 2] = ['+', ','];
+
+    /// Parses modifier keywords joined by "+" like "ALT+CONTROL" in lenient mode.
+    ///
+    /// The case of the keywords does not matter and "," or whitespace are delimiters too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is empty or has a token which is no modifier keyword.
+    pub fn parse_key_modifier(text: &str) -> Result<KeyModifiers, KeyParseError> {
+        parse_key_modifier_with(text, ParseMode::Lenient)
+    }
+
+    pub(crate) fn parse_key_modifier_with(
+        text: &str,
+        mode: ParseMode,
+    ) -> Result<KeyModifiers, KeyParseError> {
+        let text = trim_config_text(text);
+
+        if text.is_empty() {
+            return Err(KeyParseError::EmptyModifiers);
+        }
+
+        let mut result = KeyModifiers::NONE;
+        match mode {
+            ParseMode::Lenient => {
+                let mut tokens = text
+                    .split(|next: char| LENIENT_DELIMITERS.contains(&next) || next.is_whitespace())
+                    .filter(|next| !next.is_empty())
+                    .peekable();
+                if tokens.peek().is_none() {
+                    return Err(KeyParseError::EmptyModifiers);
+                }
+                for next in tokens {
+                    result |= parse_keyword(next, mode)?;
+                }
+            }
+            ParseMode::Strict => {
+                let mut token_start = 0;
+                let mut has_none = false;
+                let mut token_count = 0;
+                for next in text.split(SEPERATOR) {
+                    if next.trim().is_empty() {
+                        // The separator after the empty token or before it for the last one.
+                        let token_end = token_start + next.len();
+                        let separator = if token_end < text.len() {
+                            token_end
+                        } else {
+                            token_start - SEPERATOR.len()
+                        };
+                        let position = text[..separator].chars().count();
+                        return Err(KeyParseError::DanglingSeparator(text.to_string(), position));
+                    }
+                    has_none |= next.trim().eq_ignore_ascii_case(NONE);
+                    result |= parse_keyword(next.trim(), mode)?;
+                    token_start += next.len() + SEPERATOR.len();
+                    token_count += 1;
+                }
+                // NONE next to another keyword is a no-op and most likely a mistake.
+                if has_none && token_count > 1 {
+                    return Err(KeyParseError::NoneWithOtherModifiers(text.to_string()));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Every keyword for a modifier including alternative ones like CTRL.
+    pub(crate) fn keyword_names() -> impl Iterator<Item = &'static str> {
+        KEYWORD_NAMES.iter().copied()
+    }
+
+    pub(crate) fn is_keyword(text: &str) -> bool {
+        parse_keyword(text, ParseMode::Lenient).is_ok()
+    }
+
+    /// Compares without case instead of converting the keyword to uppercase first,
+    /// so no string is allocated for the few keywords.
+    pub(crate) fn parse_keyword(
+        keyword: &str,
+        mode: ParseMode,
+    ) -> Result<KeyModifiers, KeyParseError> {
+        if let Some(bits) = parse_hex_bits(keyword) {
+            return match mode {
+                ParseMode::Lenient => bits,
+                ParseMode::Strict => Err(KeyParseError::HexModifier(keyword.to_string())),
+            };
+        }
+        KEYWORD
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(keyword))
+            .map(|(_, modifiers)| *modifiers)
+            .ok_or_else(|| {
+                if key_code::is_modifier_key(keyword) {
+                    KeyParseError::ModifierKeyAsModifier(keyword.to_string())
+                } else {
+                    KeyParseError::UnknownModifier(keyword.to_string())
+                }
+            })
+    }
+
+    /// Reads bits without a keyword written as hex like "0x40" back.
+    /// Returns none if the token is no hex and an error for bits which have a keyword.
+    fn parse_hex_bits(token: &str) -> Option<Result<KeyModifiers, KeyParseError>> {
+        let digits = token.strip_prefix(HEX_PREFIX)?;
+        let bits = u8::from_str_radix(digits, 16).ok()?;
+        let known = KeyModifiers::from_bits_truncate(bits);
+        if known.is_empty() {
+            Some(retain_unknown_bits(token, bits))
+        } else {
+            let keywords = bit_keywords(bits).collect::<Vec<_>>().join(SEPERATOR);
+            Some(Err(KeyParseError::HexForKnownModifier(
+                token.to_string(),
+                keywords,
+            )))
+        }
+    }
+
+    /// Keeps bits without a flag, which the bitflags 2 of crossterm 0.27 allows.
+    #[cfg(feature = "crossterm-0_27")]
+    fn retain_unknown_bits(_token: &str, bits: u8) -> Result<KeyModifiers, KeyParseError> {
+        Ok(KeyModifiers::from_bits_retain(bits))
+    }
+
+    /// The bitflags 1 of crossterm 0.26 can not hold bits without a flag.
+    #[cfg(not(feature = "crossterm-0_27"))]
+    fn retain_unknown_bits(token: &str, _bits: u8) -> Result<KeyModifiers, KeyParseError> {
+        Err(KeyParseError::UnknownModifierBits(token.to_string()))
+    }
+
+    #[cfg(test)]
+    mod testing {
+        use super::*;
+        #[test]
+        fn should_accept_valid_key_modifiers() {
+            assert_case(
+                format!("{}+{}", ALT, CONTROL),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            );
+            assert_case(
+                format!("{}+{}+{}", META, NONE, SUPER),
+                KeyModifiers::META | KeyModifiers::SUPER,
+            );
+            assert_case(NONE.to_string(), KeyModifiers::NONE);
+            assert_case(
+                format!("{}+{}", CTRL, ALT),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            );
+            assert_case(
+                "alt+Control".to_string(),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            );
+            assert_case(
+                "Command+Option".to_string(),
+                KeyModifiers::SUPER | KeyModifiers::ALT,
+            );
+            fn assert_case(input: String, expected: KeyModifiers) {
+                let actual = parse_key_modifier(&input);
+                assert_eq!(expected, actual.unwrap());
+            }
+        }
+        #[test]
+        fn should_accept_platform_aliases_for_super() {
+            for input in ["SUPER", "Command", "Win", "win", "WIN"] {
+                assert_eq!(Ok(KeyModifiers::SUPER), parse_key_modifier(input));
+            }
+            assert_eq!(
+                Ok(KeyModifiers::SUPER | KeyModifiers::SHIFT),
+                parse_key_modifier("Win+Shift")
+            );
+        }
+        #[test]
+        fn should_accept_spaces_around_separator() {
+            let expected = KeyModifiers::ALT | KeyModifiers::CONTROL;
+            assert_eq!(expected, parse_key_modifier("ALT + CONTROL").unwrap());
+            assert_eq!(expected, parse_key_modifier("ALT +CONTROL").unwrap());
+            assert_eq!(expected, parse_key_modifier(" ALT+ CONTROL ").unwrap());
+        }
+        #[test]
+        fn should_accept_alternative_delimiters_in_lenient_mode() {
+            let expected = KeyModifiers::ALT | KeyModifiers::CONTROL;
+            assert_case("ALT+CONTROL", expected);
+            assert_case("ALT, CONTROL", expected);
+            assert_case("ALT,CONTROL", expected);
+            assert_case("ALT CONTROL", expected);
+            assert_case("ALT\tCONTROL", expected);
+            assert_case("ALT , CONTROL+SHIFT", expected | KeyModifiers::SHIFT);
+            fn assert_case(input: &str, expected: KeyModifiers) {
+                let actual = parse_key_modifier_with(input, ParseMode::Lenient);
+                assert_eq!(Ok(expected), actual);
+            }
+        }
+        #[test]
+        fn should_only_accept_separator_in_strict_mode() {
+            assert_eq!(
+                Ok(KeyModifiers::ALT | KeyModifiers::CONTROL),
+                parse_key_modifier_with("ALT + CONTROL", ParseMode::Strict)
+            );
+            assert!(parse_key_modifier_with("ALT, CONTROL", ParseMode::Strict).is_err());
+            assert!(parse_key_modifier_with("ALT CONTROL", ParseMode::Strict).is_err());
+        }
+        #[test]
+        fn should_deny_none_with_other_modifiers_in_strict_mode() {
+            assert_eq!(
+                Ok(KeyModifiers::NONE),
+                parse_key_modifier_with("NONE", ParseMode::Strict)
+            );
+            assert_case("META+NONE+SUPER");
+            assert_case("none+ALT");
+            assert_case("NONE+NONE");
+            assert_eq!(
+                Ok(KeyModifiers::META | KeyModifiers::SUPER),
+                parse_key_modifier_with("META+NONE+SUPER", ParseMode::Lenient)
+            );
+            assert_eq!(
+                "NONE cannot be combined with other modifiers",
+                KeyParseError::NoneWithOtherModifiers("none+ALT".to_string()).to_string()
+            );
+            fn assert_case(input: &str) {
+                let actual = parse_key_modifier_with(input, ParseMode::Strict);
+                assert_eq!(
+                    Err(KeyParseError::NoneWithOtherModifiers(input.to_string())),
+                    actual
+                );
+            }
+        }
+        #[test]
+        fn should_ignore_extra_separators_in_lenient_mode() {
+            assert_case("CONTROL+", KeyModifiers::CONTROL);
+            assert_case("+CONTROL", KeyModifiers::CONTROL);
+            assert_case("CONTROL++ALT", KeyModifiers::CONTROL | KeyModifiers::ALT);
+            fn assert_case(input: &str, expected: KeyModifiers) {
+                let actual = parse_key_modifier_with(input, ParseMode::Lenient);
+                assert_eq!(Ok(expected), actual);
+            }
+        }
+        #[test]
+        fn should_point_to_extra_separator_in_strict_mode() {
+            assert_case("CONTROL+", 7);
+            assert_case("+CONTROL", 0);
+            assert_case("CONTROL++ALT", 8);
+            assert_case("CONTROL+ +ALT", 9);
+            fn assert_case(input: &str, position: usize) {
+                let actual = parse_key_modifier_with(input, ParseMode::Strict);
+                assert_eq!(
+                    Err(KeyParseError::DanglingSeparator(
+                        input.to_string(),
+                        position
+                    )),
+                    actual
+                );
+            }
+            assert_eq!(
+                "CONTROL+ has a + without a modifier keyword next to it at position 7",
+                KeyParseError::DanglingSeparator("CONTROL+".to_string(), 7).to_string()
+            );
+        }
+        #[test]
+        fn should_deny_invalid_key_modifiers() {
+            assert_case(String::new());
+            assert_case("AL".to_string());
+            assert_case("ALT+Z".to_string());
+            fn assert_case(input: String) {
+                let actual = parse_key_modifier(&input);
+                assert!(actual.is_err());
+            }
+        }
+        #[test]
+        fn should_report_reason_for_invalid_key_modifiers() {
+            assert_eq!(Err(KeyParseError::EmptyModifiers), parse_key_modifier(" "));
+            assert_eq!(
+                Err(KeyParseError::UnknownModifier("Z".to_string())),
+                parse_key_modifier("ALT+Z")
+            );
+        }
+        #[test]
+        fn should_list_every_keyword_in_keyword_names() {
+            assert_eq!(KEYWORD.len(), KEYWORD_NAMES.len());
+            for name in KEYWORD_NAMES {
+                assert!(
+                    KEYWORD.iter().any(|(keyword, _)| *keyword == name),
+                    "{} is not a keyword",
+                    name
+                );
+            }
+        }
+        #[test]
+        fn should_convert_bits_strs() {
+            let expected = &[CONTROL, ALT];
+            let input = KeyModifiers::ALT | KeyModifiers::CONTROL;
+            let actual = bits_to_strs(&input);
+            assert_eq!(expected.as_slice(), actual.as_slice());
+        }
+        #[test]
+        fn should_write_all_modifiers_in_crossterm_order() {
+            let actual = bits_to_strs(&KeyModifiers::all());
+            assert_eq!(vec![SHIFT, CONTROL, ALT, SUPER, HYPER, META], actual);
+        }
+        #[test]
+        fn should_write_modifiers_in_canonical_order() {
+            let expected: Vec<&str> = CANONICAL_MODIFIER_ORDER
+                .iter()
+                .map(|(keyword, _)| *keyword)
+                .collect();
+            assert_eq!(expected, bits_to_strs(&KeyModifiers::all()));
+        }
+        #[test]
+        fn should_convert_none_to_one_none() {
+            let expected = &[NONE];
+            let input = KeyModifiers::empty();
+            let actual = bits_to_strs(&input);
+            assert_eq!(expected.as_slice(), actual.as_slice());
+        }
+        #[test]
+        fn should_deny_hex_for_known_bits() {
+            assert_eq!(
+                Err(KeyParseError::HexForKnownModifier(
+                    "0x02".to_string(),
+                    CONTROL.to_string()
+                )),
+                parse_key_modifier("0x02")
+            );
+            assert_eq!(
+                Err(KeyParseError::HexForKnownModifier(
+                    "0xff".to_string(),
+                    "SHIFT+CONTROL+ALT+SUPER+HYPER+META+0x40+0x80".to_string()
+                )),
+                parse_key_modifier("ALT+0xff")
+            );
+        }
+        #[test]
+        fn should_round_trip_every_modifier_combination() {
+            // Only combinations of known bits as unknown bits have no keyword.
+            for input in (0..=u8::MAX).filter_map(KeyModifiers::from_bits) {
+                let text = bits_to_strs(&input).join(SEPERATOR);
+                let actual = parse_key_modifier(&text).unwrap();
+                assert_eq!(input, actual, "Text {} did not round trip", text);
+            }
+        }
+    }
+}
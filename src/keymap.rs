@@ -0,0 +1,542 @@
+use crossterm::event::KeyEvent;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::hash::Hash;
+
+use crate::{
+    canonical_key_event_key, key_event_from_str, key_event_matches, key_event_to_string,
+    BindingKey, KeyParseError,
+};
+
+/// Two actions are bound to the same key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingConflict<A = String> {
+    pub key: BindingKey,
+    /// Action which is kept in the inverted keymap.
+    pub kept: A,
+    /// Action which is bound to the same key as the kept one and therefore left out.
+    pub dropped: A,
+}
+
+impl<A: Display> Display for BindingConflict<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Actions {} and {} are bound to the same key",
+            self.kept, self.dropped
+        )
+    }
+}
+
+/// Two actions are bound to the same key event, which [`invert_bindings`] can not map to both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateBinding<A> {
+    /// Key event of both actions with the kind press and no state.
+    pub event: KeyEvent,
+    /// One of both actions. Which one comes first is not specified.
+    pub first: A,
+    /// The other action bound to the same key event.
+    pub second: A,
+}
+
+impl<A: Display> Display for DuplicateBinding<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let event = canonical_key_event_key(&self.event);
+        write!(
+            f,
+            "Actions {} and {} are both bound to {}",
+            self.first, self.second, event
+        )
+    }
+}
+
+/// Bindings which differ between an old and a new keymap like after an upgrade of the defaults.
+///
+/// The key events are in the compact form like "CTRL+a". Every list is sorted by action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapDiff<A> {
+    /// Actions only in the new keymap with their key event.
+    pub added: Vec<(A, String)>,
+    /// Actions only in the old keymap with their key event.
+    pub removed: Vec<(A, String)>,
+    /// Actions in both keymaps with the old and the new key event.
+    pub changed: Vec<(A, String, String)>,
+}
+
+impl<A> KeymapDiff<A> {
+    /// Returns true if both keymaps have the same bindings.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares an old and a new keymap binding by binding.
+///
+/// A binding is changed only if the key events do not match like by [`key_event_matches`],
+/// so a different kind or state is not a change.
+/// A key event without compact form like a media key is written like [`canonical_key_event_key`].
+pub fn diff_keymaps<A: Eq + Hash + Clone + Display>(
+    old: &HashMap<A, KeyEvent>,
+    new: &HashMap<A, KeyEvent>,
+) -> KeymapDiff<A> {
+    fn text(event: &KeyEvent) -> String {
+        key_event_to_string(event).unwrap_or_else(|_| canonical_key_event_key(event))
+    }
+
+    let mut diff = KeymapDiff {
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+    for (action, new_event) in new {
+        match old.get(action) {
+            None => diff.added.push((action.clone(), text(new_event))),
+            Some(old_event) if !key_event_matches(old_event, new_event) => {
+                diff.changed
+                    .push((action.clone(), text(old_event), text(new_event)))
+            }
+            Some(_) => (),
+        }
+    }
+    for (action, old_event) in old {
+        if !new.contains_key(action) {
+            diff.removed.push((action.clone(), text(old_event)));
+        }
+    }
+    diff.added
+        .sort_by_cached_key(|(action, _)| action.to_string());
+    diff.removed
+        .sort_by_cached_key(|(action, _)| action.to_string());
+    diff.changed
+        .sort_by_cached_key(|(action, _, _)| action.to_string());
+    diff
+}
+
+/// Inverts a keymap from any action to key event into one from key event to action.
+///
+/// The key events of the returned map have the kind press and no state like
+/// [`KeyEvent::from`] a [`BindingKey`]. An incoming key event is reduced the same way
+/// before looking up its action, so its kind and state are ignored.
+///
+/// # Errors
+///
+/// Returns a duplicate if at least 2 actions are bound to the same key like in [`invert_keymap`].
+/// Of several duplicates the one whose key event has the lowest [`canonical_key_event_key`]
+/// is returned, so the error does not depend on the order of the map.
+pub fn invert_bindings<A: Clone>(
+    map: &HashMap<A, KeyEvent>,
+) -> Result<HashMap<KeyEvent, A>, DuplicateBinding<A>> {
+    let mut inverted: HashMap<KeyEvent, A> = HashMap::with_capacity(map.len());
+    let mut duplicates = Vec::new();
+    for (action, event) in map {
+        let event = KeyEvent::from(BindingKey::from(event));
+        if let Some(first) = inverted.get(&event) {
+            duplicates.push(DuplicateBinding {
+                event,
+                first: first.clone(),
+                second: action.clone(),
+            });
+        } else {
+            inverted.insert(event, action.clone());
+        }
+    }
+
+    match duplicates
+        .into_iter()
+        .min_by_key(|duplicate| canonical_key_event_key(&duplicate.event))
+    {
+        Some(duplicate) => Err(duplicate),
+        None => Ok(inverted),
+    }
+}
+
+/// Inverts a keymap from action to key event into one from key to action.
+///
+/// Kind and state of the key events are ignored, see [`BindingKey`].
+/// This allows to look up the action for an incoming key event.
+///
+/// # Errors
+///
+/// Returns all conflicts if at least 2 actions are bound to the same key.
+/// The actions are visited in their order like alphabetical for names. The first action of
+/// a key is kept, every following action with the same key is a conflict.
+pub fn invert_keymap<A: Ord + Clone>(
+    map: &HashMap<A, KeyEvent>,
+) -> Result<HashMap<BindingKey, A>, Vec<BindingConflict<A>>> {
+    let mut sorted: Vec<(&A, &KeyEvent)> = map.iter().collect();
+    sorted.sort_by(|left, right| left.0.cmp(right.0));
+
+    let mut inverted: HashMap<BindingKey, A> = HashMap::with_capacity(map.len());
+    let mut conflicts = Vec::new();
+    for (action, event) in sorted {
+        let key = BindingKey::from(event);
+        if let Some(kept) = inverted.get(&key) {
+            conflicts.push(BindingConflict {
+                key,
+                kept: kept.clone(),
+                dropped: action.clone(),
+            });
+        } else {
+            inverted.insert(key, action.clone());
+        }
+    }
+
+    if conflicts.is_empty() {
+        Ok(inverted)
+    } else {
+        Err(conflicts)
+    }
+}
+
+/// Parses every key event of a keymap from action to compact form like "CTRL+a".
+///
+/// Unlike deserialize it does not stop at the first invalid key event.
+/// This allows to show every problem of a config in one pass.
+///
+/// Returns the action and the reason for every invalid key event sorted by action.
+/// The returned list is empty if all key events are valid.
+pub fn validate_key_bindings(map: &HashMap<String, String>) -> Vec<(String, KeyParseError)> {
+    let mut invalid: Vec<(String, KeyParseError)> = map
+        .iter()
+        .filter_map(|(action, text)| {
+            key_event_from_str(text)
+                .err()
+                .map(|error| (action.clone(), error))
+        })
+        .collect();
+    invalid.sort_by(|left, right| left.0.cmp(&right.0));
+    invalid
+}
+
+/// Parses the pairs of action and compact form like "CTRL+a" one by one as they are pulled.
+///
+/// Unlike [`validate_key_bindings`] no map is built first, so the result of each pair can be
+/// reported while a big config is still read. The results are in the same order as the pairs.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::validate_entries;
+///
+/// let entries = vec![("save".to_string(), "CTRL+s".to_string())];
+/// let mut results = validate_entries(entries.into_iter());
+/// assert_eq!(
+///     Some(Ok(("save".to_string(), KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)))),
+///     results.next()
+/// );
+/// ```
+pub fn validate_entries<I: Iterator<Item = (String, String)>>(
+    entries: I,
+) -> impl Iterator<Item = Result<(String, KeyEvent), (String, KeyParseError)>> {
+    entries.map(|(action, text)| match key_event_from_str(&text) {
+        Ok(event) => Ok((action, event)),
+        Err(error) => Err((action, error)),
+    })
+}
+
+/// Flattens a keymap into flat keys with the compact form of the key events like "CTRL+a" as values.
+///
+/// Every flat key is the prefix followed by the action like "KEYS_move_up" for the prefix "KEYS_"
+/// or "keys.move_up" for the prefix "keys.". This is suited for flat configs like
+/// environment variables or ini files which have no nested values.
+///
+/// # Errors
+///
+/// Returns the action and the reason for every key event without a compact form sorted by action.
+pub fn keymap_to_flat(
+    prefix: &str,
+    map: &HashMap<String, KeyEvent>,
+) -> Result<HashMap<String, String>, Vec<(String, KeyParseError)>> {
+    let mut flat = HashMap::with_capacity(map.len());
+    let mut invalid = Vec::new();
+    for (action, event) in map {
+        match key_event_to_string(event) {
+            Ok(text) => {
+                flat.insert(format!("{}{}", prefix, action), text);
+            }
+            Err(error) => invalid.push((action.clone(), error)),
+        }
+    }
+    if invalid.is_empty() {
+        Ok(flat)
+    } else {
+        invalid.sort_by(|left, right| left.0.cmp(&right.0));
+        Err(invalid)
+    }
+}
+
+/// Reads a keymap back from flat keys which start with the prefix like by [`keymap_to_flat`].
+///
+/// The action is the flat key without the prefix. Flat keys without the prefix are skipped,
+/// so all environment variables can be passed in.
+///
+/// # Errors
+///
+/// Returns the flat key and the reason for every invalid key event sorted by flat key.
+pub fn keymap_from_flat(
+    prefix: &str,
+    flat: &HashMap<String, String>,
+) -> Result<HashMap<String, KeyEvent>, Vec<(String, KeyParseError)>> {
+    let mut map = HashMap::new();
+    let mut invalid = Vec::new();
+    for (key, text) in flat {
+        let Some(action) = key.strip_prefix(prefix) else {
+            continue;
+        };
+        match key_event_from_str(text) {
+            Ok(event) => {
+                map.insert(action.to_string(), event);
+            }
+            Err(error) => invalid.push((key.clone(), error)),
+        }
+    }
+    if invalid.is_empty() {
+        Ok(map)
+    } else {
+        invalid.sort_by(|left, right| left.0.cmp(&right.0));
+        Err(invalid)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEventKind, KeyEventState, KeyModifiers};
+
+    #[test]
+    fn should_validate_entries_in_order() {
+        let input = vec![
+            ("save".to_string(), "CTRL+s".to_string()),
+            ("quit".to_string(), "CTRL+".to_string()),
+            ("up".to_string(), "Up".to_string()),
+            ("down".to_string(), "Dwn".to_string()),
+        ];
+        let actual: Vec<_> = validate_entries(input.into_iter()).collect();
+        assert_eq!(
+            vec![
+                Ok((
+                    "save".to_string(),
+                    KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)
+                )),
+                Err(("quit".to_string(), key_event_from_str("CTRL+").unwrap_err())),
+                Ok((
+                    "up".to_string(),
+                    KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)
+                )),
+                Err((
+                    "down".to_string(),
+                    KeyParseError::UnknownKeyword("Dwn".to_string())
+                )),
+            ],
+            actual
+        );
+    }
+    #[test]
+    fn should_diff_keymaps() {
+        let old = HashMap::from([
+            ("save", key_event_from_str("CTRL+s").unwrap()),
+            ("quit", key_event_from_str("q").unwrap()),
+            ("up", key_event_from_str("Up").unwrap()),
+            ("help", key_event_from_str("F1").unwrap()),
+        ]);
+        let mut repeat_up = key_event_from_str("Up").unwrap();
+        repeat_up.kind = KeyEventKind::Repeat;
+        let new = HashMap::from([
+            ("save", key_event_from_str("CTRL+s").unwrap()),
+            ("quit", key_event_from_str("CTRL+q").unwrap()),
+            ("up", repeat_up),
+            ("open", key_event_from_str("CTRL+o").unwrap()),
+            ("close", key_event_from_str("CTRL+w").unwrap()),
+        ]);
+        let actual = diff_keymaps(&old, &new);
+        assert_eq!(
+            KeymapDiff {
+                added: vec![
+                    ("close", "CONTROL+w".to_string()),
+                    ("open", "CONTROL+o".to_string())
+                ],
+                removed: vec![("help", "F1".to_string())],
+                changed: vec![("quit", "q".to_string(), "CONTROL+q".to_string())],
+            },
+            actual
+        );
+        assert!(!actual.is_empty());
+        assert!(diff_keymaps(&old, &old).is_empty());
+    }
+    #[test]
+    fn should_invert_keymap() {
+        let input = HashMap::from([
+            (
+                "up".to_string(),
+                KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            ),
+            (
+                "save".to_string(),
+                KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            ),
+        ]);
+        let expected = HashMap::from([
+            (
+                BindingKey::new(KeyCode::Up, KeyModifiers::NONE),
+                "up".to_string(),
+            ),
+            (
+                BindingKey::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+                "save".to_string(),
+            ),
+        ]);
+        let actual = invert_keymap(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn should_report_conflicts() {
+        let input = HashMap::from([
+            (
+                "up".to_string(),
+                KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            ),
+            (
+                "previous".to_string(),
+                KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            ),
+            (
+                "left".to_string(),
+                KeyEvent::new(KeyCode::Left, KeyModifiers::NONE),
+            ),
+        ]);
+        let expected = vec![BindingConflict {
+            key: BindingKey::new(KeyCode::Up, KeyModifiers::NONE),
+            kept: "previous".to_string(),
+            dropped: "up".to_string(),
+        }];
+        let actual = invert_keymap(&input).unwrap_err();
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn should_validate_every_key_binding() {
+        let input = HashMap::from([
+            ("up".to_string(), "Up".to_string()),
+            ("save".to_string(), "CTRL+s".to_string()),
+            ("quit".to_string(), "Quit".to_string()),
+            ("open".to_string(), "CRTL+o".to_string()),
+            ("nothing".to_string(), "".to_string()),
+        ]);
+        let expected = vec![
+            ("nothing".to_string(), KeyParseError::EmptyInput),
+            (
+                "open".to_string(),
+                KeyParseError::UnknownModifier("CRTL".to_string()),
+            ),
+            (
+                "quit".to_string(),
+                KeyParseError::UnknownKeyword("Quit".to_string()),
+            ),
+        ];
+        let actual = validate_key_bindings(&input);
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn should_validate_valid_key_bindings() {
+        let input = HashMap::from([("up".to_string(), "Up".to_string())]);
+        assert!(validate_key_bindings(&input).is_empty());
+    }
+    #[test]
+    fn should_invert_bindings_of_any_action() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum Action {
+            Up,
+            Save,
+        }
+        let input = HashMap::from([
+            (Action::Up, KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+            (
+                Action::Save,
+                KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            ),
+        ]);
+        let actual = invert_bindings(&input).unwrap();
+        let mut incoming = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        incoming.kind = KeyEventKind::Repeat;
+        incoming.state = KeyEventState::KEYPAD;
+        let reduced = KeyEvent::from(BindingKey::from(incoming));
+        assert_eq!(Some(&Action::Up), actual.get(&reduced));
+        assert_eq!(2, actual.len());
+    }
+    #[test]
+    fn should_report_duplicate_binding() {
+        let input = HashMap::from([
+            ("up", KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+            ("previous", KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+        ]);
+        let actual = invert_bindings(&input).unwrap_err();
+        assert_eq!(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE), actual.event);
+        let mut actions = [actual.first, actual.second];
+        actions.sort_unstable();
+        assert_eq!(["previous", "up"], actions);
+    }
+    #[test]
+    fn should_report_duplicate_binding_with_lowest_canonical_key() {
+        let input = HashMap::from([
+            ("up", KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+            ("previous", KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+            (
+                "all",
+                KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            ),
+            (
+                "append",
+                KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            ),
+        ]);
+        let actual = invert_bindings(&input).unwrap_err();
+        assert_eq!(
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            actual.event
+        );
+    }
+    #[test]
+    fn should_round_trip_via_flat_keymap() {
+        let keymap = HashMap::from([
+            (
+                "move_up".to_string(),
+                KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            ),
+            (
+                "move_down".to_string(),
+                KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+            ),
+        ]);
+        let flat = keymap_to_flat("KEYS_", &keymap).unwrap();
+        assert_eq!(
+            HashMap::from([
+                ("KEYS_move_up".to_string(), "CONTROL+a".to_string()),
+                ("KEYS_move_down".to_string(), "Down".to_string()),
+            ]),
+            flat
+        );
+
+        let mut env = flat.clone();
+        env.insert("PATH".to_string(), "/usr/bin".to_string());
+        assert_eq!(keymap, keymap_from_flat("KEYS_", &env).unwrap());
+    }
+    #[test]
+    fn should_report_invalid_flat_keys() {
+        let flat = HashMap::from([
+            ("keys.quit".to_string(), "q".to_string()),
+            ("keys.save".to_string(), "CTRL+".to_string()),
+            ("keys.open".to_string(), "CTRL+oo".to_string()),
+        ]);
+        let actual = keymap_from_flat("keys.", &flat).unwrap_err();
+        assert_eq!(
+            vec![
+                (
+                    "keys.open".to_string(),
+                    KeyParseError::UnknownKeyword("oo".to_string())
+                ),
+                ("keys.save".to_string(), KeyParseError::EmptyInput),
+            ],
+            actual
+        );
+    }
+}
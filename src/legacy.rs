@@ -0,0 +1,70 @@
+use crossterm::event::KeyCode;
+use std::borrow::Cow;
+
+use crate::key_text::key_code;
+
+/// Rewrites a key code value of an old configuration into its named form.
+///
+/// Older configurations might contain a raw control char like a literal tab
+/// or a whitespace which is invisible in the file and lost by trimming.
+/// These are rewritten to their keyword like "Tab" or "Space".
+/// Every other value is returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// use crossterm_serde::upgrade_legacy_value;
+///
+/// assert_eq!("Tab", upgrade_legacy_value("\t"));
+/// assert_eq!("Space", upgrade_legacy_value(" "));
+/// assert_eq!("a", upgrade_legacy_value("a"));
+/// ```
+pub fn upgrade_legacy_value(value: &str) -> Cow<'_, str> {
+    let mut chars = value.chars();
+    let (Some(single), None) = (chars.next(), chars.next()) else {
+        return Cow::Borrowed(value);
+    };
+
+    let code = key_code::CONTROL_CHARS
+        .iter()
+        .find(|(char, _)| *char == single)
+        .map(|(_, code)| *code)
+        .unwrap_or(KeyCode::Char(single));
+    match key_code::key_code_to_text(&code) {
+        Ok(named) if named != value => named,
+        _ => Cow::Borrowed(value),
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_upgrade_control_chars() {
+        assert_case("\t", "Tab");
+        assert_case("\n", "Enter");
+        assert_case("\r", "Enter");
+        assert_case("\u{1b}", "Esc");
+        assert_case("\u{7f}", "Backspace");
+        assert_case("\u{0}", "Null");
+        assert_case(" ", "Space");
+        fn assert_case(input: &str, expected: &str) {
+            let actual = upgrade_legacy_value(input);
+            assert_eq!(expected, actual);
+        }
+    }
+    #[test]
+    fn should_keep_normal_values() {
+        assert_case("a");
+        assert_case("Up");
+        assert_case("Tab");
+        assert_case("ALT+CONTROL");
+        assert_case("");
+        fn assert_case(input: &str) {
+            let actual = upgrade_legacy_value(input);
+            assert!(matches!(actual, Cow::Borrowed(_)));
+            assert_eq!(input, actual);
+        }
+    }
+}
@@ -6,6 +6,32 @@
 //! In my view this is better suited for configuration file where the user tweaks the value to change
 //! shortcuts.
 //!
+//! # Supported crossterm versions
+//!
+//! Crossterm 0.26.1 up to 0.29 is supported. The dependency accepts any of these versions,
+//! so the key events of the crossterm of an app are used. If Cargo.lock ends up with two versions,
+//! `cargo update -p crossterm@0.29.0 --precise 0.28.1` moves this crate to the one of the app.
+//!
+//! The code without a feature compiles against every supported version.
+//! A feature like "crossterm-0_27" compiles the handling of a newer crossterm
+//! and needs at least this version in Cargo.lock:
+//!
+//! - "crossterm-0_27": modifier bits without a keyword like "0x40" are read back into
+//!   the key modifiers, which the bitflags 2 of crossterm 0.27 allows. Without it they are
+//!   rejected with `KeyParseError::UnknownModifierBits`.
+//!
+//! Every key code of crossterm is matched exhaustively on serialize. Crossterm 0.27 up to 0.29
+//! added no key code, so no arm is behind a feature yet. A key code of a newer crossterm results
+//! in a compile error until it is handled behind a feature like "crossterm-0_30".
+//! Media keys have no keyword yet and are an error on serialize.
+//!
+//! # Without std
+//!
+//! The parsing and formatting of key codes and key modifiers like [`parse_key_code`],
+//! [`key_code_to_text`], [`parse_key_modifier`] and [`bits_to_strs`] only need alloc.
+//! With `default-features = false` the crate is `no_std` and has only these functions
+//! and [`KeyParseError`]. The serde integration and every keymap need the default feature "std".
+//!
 //! # Example
 //!```
 //! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -33,7 +59,7 @@
 //!         r#"{
 //!  "move_up": {
 //!    "code": "a",
-//!    "modifiers": "ALT+CONTROL"
+//!    "modifiers": "CONTROL+ALT"
 //!  },
 //!  "move_down": {
 //!    "code": "Up",
@@ -49,5 +75,116 @@
 //! }
 //!```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod binding_key;
+#[cfg(feature = "std")]
+mod binding_pattern;
+#[cfg(feature = "std")]
+mod codec;
+#[cfg(feature = "std")]
+mod compact;
+#[cfg(feature = "std")]
+mod config_modifiers;
+#[cfg(feature = "std")]
+mod counted_key_event;
+#[cfg(feature = "ron")]
+mod default_keymap;
+#[cfg(feature = "std")]
+mod display;
+mod error;
+#[cfg(feature = "std")]
+mod flat_struct;
+#[cfg(feature = "std")]
+mod json_schema;
+#[cfg(feature = "std")]
+mod key_bindings;
+#[cfg(feature = "std")]
 mod key_event_serde;
-pub use key_event_serde::SerDeConfigKeyEvent;
+#[cfg(feature = "std")]
+mod key_macro;
+mod key_text;
+#[cfg(feature = "std")]
+mod keymap;
+#[cfg(feature = "std")]
+mod legacy;
+#[cfg(feature = "std")]
+mod match_spec;
+#[cfg(feature = "native")]
+mod native;
+#[cfg(feature = "std")]
+pub mod prelude;
+#[cfg(feature = "indexmap")]
+pub mod serde_index_keymap;
+#[cfg(feature = "std")]
+pub mod serde_key_event_compact;
+#[cfg(feature = "std")]
+pub mod serde_key_event_opt;
+#[cfg(feature = "std")]
+pub mod serde_keymap;
+#[cfg(feature = "std")]
+mod sided_key_event;
+#[cfg(feature = "std")]
+pub use binding_key::{key_event_matches, key_event_matches_keypad, BindingKey, MatchKey};
+#[cfg(feature = "std")]
+pub use binding_pattern::BindingPattern;
+#[cfg(feature = "std")]
+pub use codec::{
+    BackTabNormalization, BlankModifiers, CustomKeyCodes, EmptyModifiers, KeyEventCodec,
+    KeyEventCodecBuilder, KeypadField, KindField, ModifierCase, ModifierNaming, ModifierSort,
+    ModifiersStyle, NoneModifiers, ShiftNormalization, UnknownFields,
+};
+#[cfg(feature = "std")]
+pub use compact::{canonical_key_event_key, key_event_from_str, key_event_to_string};
+#[cfg(feature = "std")]
+pub use config_modifiers::ConfigModifiers;
+#[cfg(feature = "std")]
+pub use counted_key_event::CountedKeyEvent;
+#[cfg(feature = "ron")]
+pub use default_keymap::load_default_keymap;
+#[cfg(feature = "modifier_symbols")]
+pub use display::modifier_symbols;
+#[cfg(feature = "std")]
+pub use display::{display_name, format_bindings_table, DisplayStyle};
+pub use error::KeyParseError;
+#[cfg(feature = "std")]
+pub use flat_struct::{struct_from_flat, struct_to_flat, FlatError};
+#[cfg(feature = "std")]
+pub use json_schema::key_event_json_schema;
+#[cfg(feature = "schemars")]
+pub use json_schema::KeyEventSchema;
+#[cfg(feature = "std")]
+pub use key_bindings::KeyBindings;
+#[cfg(feature = "std")]
+pub use key_event_serde::{
+    serde_key_event_kind, serde_key_modifier, KeyEventDef, SerDeConfigKeyEvent,
+    SerDeConfigKeyEventAnnotated, SerDeConfigKeyEventWithKind,
+};
+pub use key_text::key_code::{
+    iter_representable_key_codes, key_code_to_text, parse_key_code,
+    parse_key_code_with_diagnostics, AliasUsed, KEY_CODE_ALIASES,
+};
+pub use key_text::key_modifier::{bits_to_strs, parse_key_modifier, CANONICAL_MODIFIER_ORDER};
+pub use key_text::ParseMode;
+#[cfg(feature = "std")]
+pub use keymap::{
+    diff_keymaps, invert_bindings, invert_keymap, keymap_from_flat, keymap_to_flat,
+    validate_entries, validate_key_bindings, BindingConflict, DuplicateBinding, KeymapDiff,
+};
+#[cfg(feature = "std")]
+pub use legacy::upgrade_legacy_value;
+#[cfg(feature = "std")]
+pub use match_spec::MatchSpec;
+#[cfg(feature = "native")]
+pub use native::{from_native_json, to_native_json};
+#[cfg(feature = "std")]
+pub use sided_key_event::SidedKeyEvent;
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::key_macro::check_modifier_keywords;
+}
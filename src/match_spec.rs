@@ -0,0 +1,182 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+use crate::compact;
+use crate::key_text::key_modifier;
+use crate::KeyParseError;
+
+const NEGATION: char = '!';
+
+/// Pattern for key events which requires some modifiers to be pressed and others to be not pressed.
+///
+/// The text is the compact form of a key event followed by negated modifiers, each separated by
+/// whitespace, like "a !SHIFT" or "CONTROL+s !ALT !SHIFT".
+/// The modifiers of the key event are required to be pressed and the negated ones must not be pressed.
+/// Modifiers which are neither required nor negated may be pressed or not.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::MatchSpec;
+///
+/// let spec: MatchSpec = "CONTROL+s !SHIFT".parse().unwrap();
+/// assert!(spec.matches(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)));
+/// assert!(spec.matches(&KeyEvent::new(
+///     KeyCode::Char('s'),
+///     KeyModifiers::CONTROL | KeyModifiers::ALT
+/// )));
+/// assert!(!spec.matches(&KeyEvent::new(
+///     KeyCode::Char('s'),
+///     KeyModifiers::CONTROL | KeyModifiers::SHIFT
+/// )));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatchSpec {
+    pub code: KeyCode,
+    /// Modifiers which must be pressed.
+    pub present: KeyModifiers,
+    /// Modifiers which must not be pressed.
+    pub absent: KeyModifiers,
+}
+
+impl MatchSpec {
+    /// Returns true if the code is the same, all present modifiers are pressed
+    /// and none of the absent modifiers are pressed. Kind and state are ignored.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        self.code == event.code
+            && event.modifiers.contains(self.present)
+            && !event.modifiers.intersects(self.absent)
+    }
+
+    fn to_text(self) -> Result<String, KeyParseError> {
+        let mut text = compact::key_event_to_string(&KeyEvent::new(self.code, self.present))?;
+        if !self.absent.is_empty() {
+            for next in key_modifier::bits_to_strs(&self.absent) {
+                text.push(' ');
+                text.push(NEGATION);
+                text.push_str(next);
+            }
+        }
+        Ok(text)
+    }
+}
+
+impl FromStr for MatchSpec {
+    type Err = KeyParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut tokens = text.split_whitespace();
+        let event = compact::key_event_from_str(tokens.next().unwrap_or_default())?;
+
+        let mut absent = KeyModifiers::NONE;
+        for next in tokens {
+            let negated = next
+                .strip_prefix(NEGATION)
+                .ok_or_else(|| KeyParseError::MissingNegation(next.to_string()))?;
+            absent |= key_modifier::parse_key_modifier(negated)?;
+        }
+
+        let both = event.modifiers & absent;
+        if !both.is_empty() {
+            let both = key_modifier::bits_to_strs(&both).join(key_modifier::SEPERATOR);
+            return Err(KeyParseError::RequiredAndNegated(both));
+        }
+
+        Ok(Self {
+            code: event.code,
+            present: event.modifiers,
+            absent,
+        })
+    }
+}
+
+impl Serialize for MatchSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let text = self.to_text().map_err(ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_parse_negated_modifiers() {
+        assert_case("a", KeyModifiers::NONE, KeyModifiers::NONE);
+        assert_case("a !SHIFT", KeyModifiers::NONE, KeyModifiers::SHIFT);
+        assert_case(
+            "CONTROL+a !SHIFT !ALT",
+            KeyModifiers::CONTROL,
+            KeyModifiers::SHIFT | KeyModifiers::ALT,
+        );
+        fn assert_case(input: &str, present: KeyModifiers, absent: KeyModifiers) {
+            let actual: MatchSpec = input.parse().unwrap();
+            let expected = MatchSpec {
+                code: KeyCode::Char('a'),
+                present,
+                absent,
+            };
+            assert_eq!(expected, actual);
+        }
+    }
+    #[test]
+    fn should_deny_invalid_negation() {
+        assert_case("a SHIFT");
+        assert_case("a !Z");
+        assert_case("SHIFT+a !SHIFT");
+        fn assert_case(input: &str) {
+            assert!(input.parse::<MatchSpec>().is_err());
+        }
+        assert_eq!(
+            Err(KeyParseError::MissingNegation("SHIFT".to_string())),
+            "a SHIFT".parse::<MatchSpec>()
+        );
+    }
+    #[test]
+    fn should_require_present_modifiers() {
+        let spec: MatchSpec = "CONTROL+a".parse().unwrap();
+        assert_case(&spec, KeyModifiers::CONTROL, true);
+        assert_case(&spec, KeyModifiers::CONTROL | KeyModifiers::SHIFT, true);
+        assert_case(&spec, KeyModifiers::NONE, false);
+        assert_case(&spec, KeyModifiers::ALT, false);
+    }
+    #[test]
+    fn should_require_absent_modifiers() {
+        let spec: MatchSpec = "a !SHIFT !ALT".parse().unwrap();
+        assert_case(&spec, KeyModifiers::NONE, true);
+        assert_case(&spec, KeyModifiers::CONTROL, true);
+        assert_case(&spec, KeyModifiers::SHIFT, false);
+        assert_case(&spec, KeyModifiers::ALT | KeyModifiers::CONTROL, false);
+    }
+    #[test]
+    fn should_round_trip_via_serde() {
+        assert_case(r#""a""#);
+        assert_case(r#""CONTROL+a !SHIFT !ALT""#);
+        fn assert_case(input: &str) {
+            let spec: MatchSpec = serde_json::from_str(input).unwrap();
+            let actual = serde_json::to_string(&spec).unwrap();
+            assert_eq!(input, actual);
+        }
+    }
+
+    fn assert_case(spec: &MatchSpec, modifiers: KeyModifiers, expected: bool) {
+        let event = KeyEvent::new(KeyCode::Char('a'), modifiers);
+        assert_eq!(expected, spec.matches(&event), "Modifiers: {:?}", modifiers);
+    }
+}
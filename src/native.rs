@@ -0,0 +1,79 @@
+use crossterm::event::KeyEvent;
+use serde::Deserialize;
+
+use crate::KeyParseError;
+
+/// Reads a key event in the serde representation of crossterm from JSON.
+///
+/// This allows to migrate a config written with the serde feature of crossterm
+/// like `{ "code": { "Char": "a" }, "modifiers": { "bits": 2 }, ... }` to the readable form.
+/// Kind and state are kept as they are in the JSON.
+///
+/// The representation is the one of the crossterm in use. Crossterm 0.26 writes the modifiers
+/// as bits like `{ "bits": 2 }`, crossterm 0.27 and newer as flags like `"CONTROL | ALT"`.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use crossterm_serde::{from_native_json, key_event_to_string, to_native_json};
+///
+/// let native = to_native_json(&KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+/// assert_eq!(serde_json::json!({ "Char": "a" }), native["code"]);
+/// let event = from_native_json(&native).unwrap();
+/// assert_eq!(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL), event);
+/// assert_eq!("CONTROL+a", key_event_to_string(&event).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the JSON is not a key event of crossterm.
+pub fn from_native_json(value: &serde_json::Value) -> Result<KeyEvent, KeyParseError> {
+    KeyEvent::deserialize(value).map_err(|error| KeyParseError::InvalidNative(error.to_string()))
+}
+
+/// Writes a key event in the serde representation of crossterm as JSON.
+///
+/// This is the counterpart of [`from_native_json`] for tools which need the old form.
+pub fn to_native_json(event: &KeyEvent) -> serde_json::Value {
+    serde_json::to_value(event).expect("A key event of crossterm is always valid JSON")
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::{key_event_from_str, key_event_to_string};
+    use crossterm::event::{KeyCode, KeyEventKind, KeyEventState, KeyModifiers};
+
+    #[test]
+    fn should_round_trip_through_both_forms() {
+        let readable = "CONTROL+ALT+a";
+        let event = key_event_from_str(readable).unwrap();
+        let native = to_native_json(&event);
+        assert_eq!(serde_json::json!({ "Char": "a" }), native["code"]);
+        assert_eq!("Press", native["kind"]);
+        let back = from_native_json(&native).unwrap();
+        assert_eq!(event, back);
+        assert_eq!(readable, key_event_to_string(&back).unwrap());
+    }
+    #[test]
+    fn should_keep_kind_and_state_of_native_form() {
+        let event = KeyEvent::new_with_kind_and_state(
+            KeyCode::F(5),
+            KeyModifiers::SHIFT,
+            KeyEventKind::Release,
+            KeyEventState::KEYPAD,
+        );
+        let back = from_native_json(&to_native_json(&event)).unwrap();
+        assert_eq!(KeyEventKind::Release, back.kind);
+        assert_eq!(KeyEventState::KEYPAD, back.state);
+    }
+    #[test]
+    fn should_deny_invalid_native_form() {
+        let actual = from_native_json(&serde_json::json!({ "code": "a" })).unwrap_err();
+        assert_eq!(
+            KeyParseError::InvalidNative("unknown variant `a`, expected one of `Backspace`, `Enter`, `Left`, `Right`, `Up`, `Down`, `Home`, `End`, `PageUp`, `PageDown`, `Tab`, `BackTab`, `Delete`, `Insert`, `F`, `Char`, `Null`, `Esc`, `CapsLock`, `ScrollLock`, `NumLock`, `PrintScreen`, `Pause`, `Menu`, `KeypadBegin`, `Media`, `Modifier`".to_string()),
+            actual
+        );
+    }
+}
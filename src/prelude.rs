@@ -0,0 +1,40 @@
+//! Re-exports of the items most configs need, so one import is enough.
+//!
+//! # Example
+//!
+//! ```
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use crossterm_serde::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+//! pub struct Config {
+//!     #[serde(with = "SerDeConfigKeyEvent")]
+//!     quit: KeyEvent,
+//!     #[serde(with = "serde_key_modifier")]
+//!     select: KeyModifiers,
+//!     leader: ConfigModifiers,
+//! }
+//!
+//! let config = Config {
+//!     quit: key_event_from_str("CTRL+q").unwrap(),
+//!     select: KeyModifiers::SHIFT,
+//!     leader: ConfigModifiers(KeyModifiers::ALT),
+//! };
+//! let string = serde_json::to_string(&config).unwrap();
+//! assert_eq!(
+//!     r#"{"quit":{"code":"q","modifiers":"CONTROL"},"select":"SHIFT","leader":"ALT"}"#,
+//!     string
+//! );
+//! assert_eq!(config, serde_json::from_str(&string).unwrap());
+//! assert_eq!("CONTROL+q", key_event_to_string(&config.quit).unwrap());
+//! let error: KeyParseError = key_event_from_str("CTRL+").unwrap_err();
+//! assert_eq!(KeyParseError::EmptyInput, error);
+//! ```
+
+pub use crate::key;
+pub use crate::{
+    key_event_from_str, key_event_to_string, serde_key_event_kind, serde_key_event_opt,
+    serde_key_modifier, serde_keymap, ConfigModifiers, KeyEventCodec, KeyEventDef, KeyParseError,
+    SerDeConfigKeyEvent, SerDeConfigKeyEventWithKind,
+};
@@ -0,0 +1,103 @@
+//! Serde for a keymap like `IndexMap<String, KeyEvent>` with the feature "indexmap" and the
+//! readable key events of [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent).
+//!
+//! Unlike [`serde_keymap`](crate::serde_keymap) the entries are not sorted but written in the
+//! order of the map. The order is the one of the config file after deserialize,
+//! so the bindings stay in the order a human gave them.
+//!
+//! # Example
+//!
+//! ```
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use indexmap::IndexMap;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+//! pub struct Config {
+//!     #[serde(with = "crossterm_serde::serde_index_keymap")]
+//!     keymap: IndexMap<String, KeyEvent>,
+//! }
+//!
+//! let config = Config {
+//!     keymap: IndexMap::from([
+//!         ("up".to_string(), KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+//!         ("down".to_string(), KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)),
+//!     ]),
+//! };
+//! let string = serde_json::to_string(&config).unwrap();
+//! assert_eq!(
+//!     r#"{"keymap":{"up":{"code":"Up","modifiers":"NONE"},"down":{"code":"Down","modifiers":"NONE"}}}"#,
+//!     string
+//! );
+//! assert_eq!(config, serde_json::from_str(&string).unwrap());
+//! ```
+
+use crossterm::event::KeyEvent;
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::hash::Hash;
+
+use crate::key_event_serde::KeyEventDef;
+
+pub fn serialize<K, S>(map: &IndexMap<K, KeyEvent>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Eq + Hash,
+    S: Serializer,
+{
+    let ordered: IndexMap<&K, KeyEventDef> = map
+        .iter()
+        .map(|(key, event)| (key, KeyEventDef(*event)))
+        .collect();
+    ordered.serialize(serializer)
+}
+
+pub fn deserialize<'de, K, D>(deserializer: D) -> Result<IndexMap<K, KeyEvent>, D::Error>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    D: Deserializer<'de>,
+{
+    let map = IndexMap::<K, KeyEventDef>::deserialize(deserializer)?;
+    Ok(map
+        .into_iter()
+        .map(|(key, KeyEventDef(event))| (key, event))
+        .collect())
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+    struct Config {
+        #[serde(with = "super")]
+        keymap: IndexMap<String, KeyEvent>,
+    }
+
+    #[test]
+    fn should_keep_order_of_config() {
+        let input = r#"
+(
+    keymap: {
+        "quit": (code: "q", modifiers: "CONTROL"),
+        "move_up": (code: "k"),
+        "accept": (code: "Enter"),
+    },
+)
+        "#;
+        let actual: Config = ron::from_str(input).unwrap();
+        let actions: Vec<&str> = actual.keymap.keys().map(String::as_str).collect();
+        assert_eq!(vec!["quit", "move_up", "accept"], actions);
+        assert_eq!(
+            Some(&KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+            actual.keymap.get("quit")
+        );
+
+        let text = serde_json::to_string(&actual).unwrap();
+        assert_eq!(
+            r#"{"keymap":{"quit":{"code":"q","modifiers":"CONTROL"},"move_up":{"code":"k","modifiers":"NONE"},"accept":{"code":"Enter","modifiers":"NONE"}}}"#,
+            text
+        );
+        assert_eq!(actual, serde_json::from_str(&text).unwrap());
+    }
+}
@@ -0,0 +1,84 @@
+//! Serde for a key event in its compact form like "CTRL+a" instead of an object with
+//! a code and modifiers field.
+//!
+//! This suits formats whose values are only texts like environment variables or ini files,
+//! see [`struct_to_flat`](crate::struct_to_flat). On deserialize the object form of
+//! [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent) is accepted as well.
+//!
+//! # Example
+//!
+//! ```
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+//! pub struct KeyBoard {
+//!     #[serde(with = "crossterm_serde::serde_key_event_compact")]
+//!     save: KeyEvent,
+//! }
+//!
+//! let key_board = KeyBoard {
+//!     save: KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+//! };
+//! let string = serde_json::to_string(&key_board).unwrap();
+//! assert_eq!(r#"{"save":"CONTROL+s"}"#, string);
+//! assert_eq!(key_board, serde_json::from_str(&string).unwrap());
+//! ```
+
+use crossterm::event::KeyEvent;
+use serde::{ser, Deserialize, Deserializer, Serializer};
+
+use crate::key_event_serde::KeyEventDef;
+use crate::key_event_to_string;
+
+/// # Errors
+///
+/// Returns an error if the key code has no compact form like a media key.
+pub fn serialize<S>(event: &KeyEvent, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let text = key_event_to_string(event).map_err(ser::Error::custom)?;
+    serializer.serialize_str(&text)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyEvent, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    KeyEventDef::deserialize(deserializer).map(|KeyEventDef(event)| event)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers, MediaKeyCode};
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    struct Binding {
+        #[serde(with = "super")]
+        key: KeyEvent,
+    }
+
+    #[test]
+    fn should_round_trip_compact_form_in_ron() {
+        let binding = Binding {
+            key: KeyEvent::new(KeyCode::Up, KeyModifiers::ALT),
+        };
+        let ron = ron::to_string(&binding).unwrap();
+        assert_eq!(r#"(key:"ALT+Up")"#, ron);
+        assert_eq!(binding, ron::from_str(&ron).unwrap());
+        assert_eq!(
+            binding,
+            ron::from_str(r#"(key: (code: "Up", modifiers: "ALT"))"#).unwrap()
+        );
+    }
+    #[test]
+    fn should_deny_key_code_without_compact_form() {
+        let binding = Binding {
+            key: KeyEvent::new(KeyCode::Media(MediaKeyCode::Play), KeyModifiers::NONE),
+        };
+        assert!(ron::to_string(&binding).is_err());
+    }
+}
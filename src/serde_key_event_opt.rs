@@ -0,0 +1,88 @@
+//! Serde for an optional key event like `Option<KeyEvent>` with the readable key event of
+//! [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent).
+//!
+//! This allows optional bindings where `None` means the action is not bound to any key.
+//! `None` is written as `null`. With `#[serde(default, skip_serializing_if = "Option::is_none")]`
+//! on the field, `None` is left out instead and a missing field is read as `None`.
+//!
+//! # Example
+//!
+//! ```
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+//! pub struct KeyBoard {
+//!     #[serde(with = "crossterm_serde::serde_key_event_opt")]
+//!     move_up: Option<KeyEvent>,
+//!     #[serde(
+//!         default,
+//!         skip_serializing_if = "Option::is_none",
+//!         with = "crossterm_serde::serde_key_event_opt"
+//!     )]
+//!     move_down: Option<KeyEvent>,
+//! }
+//!
+//! let key_board = KeyBoard {
+//!     move_up: Some(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+//!     move_down: None,
+//! };
+//! let string = serde_json::to_string(&key_board).unwrap();
+//! assert_eq!(r#"{"move_up":{"code":"Up","modifiers":"NONE"}}"#, string);
+//! assert_eq!(key_board, serde_json::from_str(&string).unwrap());
+//!
+//! let unbound = KeyBoard {
+//!     move_up: None,
+//!     move_down: None,
+//! };
+//! let string = serde_json::to_string(&unbound).unwrap();
+//! assert_eq!(r#"{"move_up":null}"#, string);
+//! assert_eq!(unbound, serde_json::from_str(&string).unwrap());
+//! ```
+
+use crossterm::event::KeyEvent;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::key_event_serde::KeyEventDef;
+
+pub fn serialize<S>(event: &Option<KeyEvent>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    event.map(KeyEventDef).serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<KeyEvent>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let event = Option::<KeyEventDef>::deserialize(deserializer)?;
+    Ok(event.map(|KeyEventDef(event)| event))
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    struct Binding {
+        #[serde(with = "super")]
+        key: Option<KeyEvent>,
+    }
+
+    #[test]
+    fn should_round_trip_optional_key_event_in_ron() {
+        let bound = Binding {
+            key: Some(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL)),
+        };
+        let ron = ron::to_string(&bound).unwrap();
+        assert_eq!(r#"(key:Some((code:"a",modifiers:"CONTROL")))"#, ron);
+        assert_eq!(bound, ron::from_str(&ron).unwrap());
+
+        let unbound = Binding { key: None };
+        let ron = ron::to_string(&unbound).unwrap();
+        assert_eq!("(key:None)", ron);
+        assert_eq!(unbound, ron::from_str(&ron).unwrap());
+    }
+}
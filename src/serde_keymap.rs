@@ -0,0 +1,445 @@
+//! Serde for a whole keymap like `HashMap<String, KeyEvent>` with the readable key events of
+//! [`SerDeConfigKeyEvent`](crate::SerDeConfigKeyEvent).
+//!
+//! The entries of a `HashMap` come in a random order. On serialize the entries are sorted by
+//! their key, so the same keymap is always written the same way.
+//! An empty keymap is written as an empty map.
+//!
+//! # Example
+//!
+//! ```
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+//! pub struct Config {
+//!     #[serde(with = "crossterm_serde::serde_keymap")]
+//!     keymap: HashMap<String, KeyEvent>,
+//! }
+//!
+//! let config = Config {
+//!     keymap: HashMap::from([
+//!         ("up".to_string(), KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+//!         ("down".to_string(), KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)),
+//!     ]),
+//! };
+//! let string = serde_json::to_string(&config).unwrap();
+//! assert_eq!(
+//!     r#"{"keymap":{"down":{"code":"Down","modifiers":"NONE"},"up":{"code":"Up","modifiers":"NONE"}}}"#,
+//!     string
+//! );
+//! assert_eq!(config, serde_json::from_str(&string).unwrap());
+//! ```
+//!
+//! A TOML serializer writes every binding as its own table like `[keymap.up]`.
+//! Each binding can be written as an inline table like `up = { code = "Up", modifiers = "NONE" }`
+//! by the value serializer of toml instead. The key code is a plain string, so punctuation like
+//! '#' or '=' is quoted by TOML and read back as it was.
+//!
+//! ```
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use crossterm_serde::KeyEventDef;
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Debug, Deserialize)]
+//! pub struct Config {
+//!     #[serde(with = "crossterm_serde::serde_keymap")]
+//!     keymap: HashMap<String, KeyEvent>,
+//! }
+//!
+//! let comment = KeyEventDef(KeyEvent::new(KeyCode::Char('#'), KeyModifiers::CONTROL));
+//! let mut inline = String::new();
+//! comment.serialize(toml::ser::ValueSerializer::new(&mut inline)).unwrap();
+//! assert_eq!(r##"{ code = "#", modifiers = "CONTROL" }"##, inline);
+//!
+//! let config: Config = toml::from_str(&format!("[keymap]\ncomment = {}", inline)).unwrap();
+//! assert_eq!(Some(&comment.0), config.keymap.get("comment"));
+//! ```
+
+use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use crate::key_event_serde::KeyEventDef;
+use crate::key_text::key_code::{self, FUNCTION_KEYS};
+use crate::key_text::{key_modifier, trim_config_text};
+use crate::{compact, KeyParseError, ParseMode};
+
+pub fn serialize<K, S>(map: &HashMap<K, KeyEvent>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Ord,
+    S: Serializer,
+{
+    let sorted: BTreeMap<&K, KeyEventDef> = map
+        .iter()
+        .map(|(key, event)| (key, KeyEventDef(*event)))
+        .collect();
+    sorted.serialize(serializer)
+}
+
+pub fn deserialize<'de, K, D>(deserializer: D) -> Result<HashMap<K, KeyEvent>, D::Error>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    D: Deserializer<'de>,
+{
+    let map = HashMap::<K, KeyEventDef>::deserialize(deserializer)?;
+    Ok(map
+        .into_iter()
+        .map(|(key, KeyEventDef(event))| (key, event))
+        .collect())
+}
+
+/// Like [`deserialize`] for a keymap of action names, but an error names the action
+/// and the field of the invalid key event like "move_up.modifiers: AL is not a valid keyword".
+///
+/// The error of the format like serde_json adds the line and column. The path of the keymap
+/// itself inside the config is not known here, a crate like serde_path_to_error can add it.
+/// Actions are checked in alphabetical order, so the same config always gives the same error.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::KeyEvent;
+/// use serde::Deserialize;
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, Deserialize)]
+/// pub struct Config {
+///     #[serde(deserialize_with = "crossterm_serde::serde_keymap::deserialize_bindings_with_path")]
+///     keyboard: HashMap<String, KeyEvent>,
+/// }
+///
+/// let error = serde_json::from_str::<Config>(
+///     r#"{"keyboard":{"move_up":{"code":"Up","modifiers":"AL"},"quit":"q"}}"#,
+/// )
+/// .unwrap_err();
+/// assert_eq!(
+///     "move_up.modifiers: AL is not a valid keyword at line 1 column 66",
+///     error.to_string()
+/// );
+/// ```
+pub fn deserialize_bindings_with_path<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, KeyEvent>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let sorted = BTreeMap::<String, RawBinding>::deserialize(deserializer)?;
+    sorted
+        .into_iter()
+        .map(|(action, raw)| match raw.parse() {
+            Ok(event) => Ok((action, event)),
+            Err((field, error)) => Err(de::Error::custom(format_args!(
+                "{}{}: {}",
+                action, field, error
+            ))),
+        })
+        .collect()
+}
+
+/// Binding which is read even if it is no key event at all, so the errors of every binding
+/// in a keymap can be reported at once.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum MaybeBinding {
+    Binding(RawBinding),
+    Invalid(de::IgnoredAny),
+}
+
+impl MaybeBinding {
+    /// Returns an error like "move_up.modifiers: AL is not a valid keyword" if invalid.
+    pub(crate) fn parse(self, action: &str) -> Result<KeyEvent, String> {
+        match self {
+            MaybeBinding::Binding(raw) => raw
+                .parse()
+                .map_err(|(field, error)| format!("{}{}: {}", action, field, error)),
+            MaybeBinding::Invalid(_) => Err(format!(
+                "{}: expected a key event like \"CTRL+a\" or {{ code: \"a\" }}",
+                action
+            )),
+        }
+    }
+}
+
+/// Key event of a binding before its texts are parsed, so an error can name the field.
+/// The shapes are the same as the default deserialize accepts.
+#[derive(Deserialize)]
+#[serde(untagged, expecting = "a key event like \"CTRL+a\" or { code: \"a\" }")]
+pub(crate) enum RawBinding {
+    Compact(String),
+    Structured {
+        code: RawCode,
+        modifiers: Option<RawModifiers>,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum RawCode {
+    Text(String),
+    Function { f: u8 },
+    Raw { raw: u32 },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum RawModifiers {
+    Joined(String),
+    Keywords(Vec<String>),
+    Flags(BTreeMap<String, bool>),
+}
+
+impl RawBinding {
+    /// Returns the path of the invalid field after the action like ".code" on error.
+    fn parse(self) -> Result<KeyEvent, (&'static str, KeyParseError)> {
+        let (code, modifiers) = match self {
+            RawBinding::Compact(text) => {
+                return compact::key_event_from_str(trim_config_text(&text))
+                    .map_err(|error| ("", error))
+            }
+            RawBinding::Structured { code, modifiers } => (code, modifiers),
+        };
+
+        let code = match code {
+            RawCode::Text(text) => key_code::parse_key_code(trim_config_text(&text)),
+            RawCode::Function { f } if FUNCTION_KEYS.contains(&f) => Ok(KeyCode::F(f)),
+            RawCode::Function { f } => Err(KeyParseError::OutOfRangeFunctionKey(f)),
+            RawCode::Raw { raw } => char::from_u32(raw)
+                .map(KeyCode::Char)
+                .ok_or(KeyParseError::InvalidRawCode(raw)),
+        }
+        .map_err(|error| (".code", error))?;
+        let modifiers = match modifiers {
+            None => Ok(KeyModifiers::NONE),
+            Some(RawModifiers::Joined(text)) => {
+                key_modifier::parse_key_modifier_with(&text, ParseMode::Lenient)
+            }
+            Some(RawModifiers::Keywords(keywords)) if keywords.is_empty() => {
+                Err(KeyParseError::EmptyModifiers)
+            }
+            Some(RawModifiers::Keywords(keywords)) => {
+                keywords
+                    .iter()
+                    .try_fold(KeyModifiers::NONE, |result, keyword| {
+                        key_modifier::parse_key_modifier_with(keyword, ParseMode::Strict)
+                            .map(|next| result | next)
+                    })
+            }
+            Some(RawModifiers::Flags(flags)) => flags
+                .iter()
+                .filter(|(_, pressed)| **pressed)
+                .try_fold(KeyModifiers::NONE, |result, (keyword, _)| {
+                    key_modifier::parse_key_modifier_with(keyword, ParseMode::Strict)
+                        .map(|next| result | next)
+                }),
+        }
+        .map_err(|error| (".modifiers", error))?;
+        Ok(KeyEvent::new(code, modifiers))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::SerDeConfigKeyEvent;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+    struct Config {
+        #[serde(with = "super")]
+        keymap: HashMap<String, KeyEvent>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    struct KeyBoard {
+        #[serde(with = "SerDeConfigKeyEvent")]
+        move_up: KeyEvent,
+        #[serde(with = "SerDeConfigKeyEvent")]
+        move_down: KeyEvent,
+    }
+
+    impl Default for KeyBoard {
+        fn default() -> Self {
+            Self {
+                move_up: KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+                move_down: KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+            }
+        }
+    }
+
+    #[test]
+    fn should_serialize_empty_keymap() {
+        let input = Config::default();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(r#"{"keymap":{}}"#, json);
+        assert_eq!(input, serde_json::from_str(&json).unwrap());
+
+        let ron = ron::to_string(&input).unwrap();
+        assert_eq!("(keymap:{})", ron);
+        assert_eq!(input, ron::from_str(&ron).unwrap());
+    }
+    #[test]
+    fn should_serialize_default_struct() {
+        let input = KeyBoard::default();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            r#"{"move_up":{"code":"Up","modifiers":"NONE"},"move_down":{"code":"Down","modifiers":"NONE"}}"#,
+            json
+        );
+        assert_eq!(input, serde_json::from_str(&json).unwrap());
+
+        let ron = ron::to_string(&input).unwrap();
+        assert_eq!(
+            r#"(move_up:(code:"Up",modifiers:"NONE"),move_down:(code:"Down",modifiers:"NONE"))"#,
+            ron
+        );
+        assert_eq!(input, ron::from_str(&ron).unwrap());
+    }
+    #[test]
+    fn should_round_trip_inline_tables_in_toml() {
+        let input = r##"
+[keymap]
+move_up = { code = "Up", modifiers = "NONE" }
+comment = { code = "#", modifiers = "CONTROL" }
+assign = { code = "=" }
+quote = { code = '"', modifiers = "SHIFT" }
+backslash = { code = '\', modifiers = "ALT" }
+open = { code = "[" }
+close = { code = "}" }
+"##;
+        let actual: Config = toml::from_str(input).unwrap();
+        let expected: HashMap<String, KeyEvent> = [
+            ("move_up", KeyCode::Up, KeyModifiers::NONE),
+            ("comment", KeyCode::Char('#'), KeyModifiers::CONTROL),
+            ("assign", KeyCode::Char('='), KeyModifiers::NONE),
+            ("quote", KeyCode::Char('"'), KeyModifiers::SHIFT),
+            ("backslash", KeyCode::Char('\\'), KeyModifiers::ALT),
+            ("open", KeyCode::Char('['), KeyModifiers::NONE),
+            ("close", KeyCode::Char('}'), KeyModifiers::NONE),
+        ]
+        .into_iter()
+        .map(|(action, code, modifiers)| (action.to_string(), KeyEvent::new(code, modifiers)))
+        .collect();
+        assert_eq!(expected, actual.keymap);
+
+        let mut written = String::from("[keymap]\n");
+        let mut sorted: Vec<(&String, &KeyEvent)> = actual.keymap.iter().collect();
+        sorted.sort_by_key(|(action, _)| *action);
+        for (action, event) in sorted {
+            let mut inline = String::new();
+            KeyEventDef(*event)
+                .serialize(toml::ser::ValueSerializer::new(&mut inline))
+                .unwrap();
+            written.push_str(&format!("{} = {}\n", action, inline));
+        }
+        assert!(
+            written.contains(r#"quote = { code = '"', modifiers = "SHIFT" }"#),
+            "{}",
+            written
+        );
+        let back: Config = toml::from_str(&written).unwrap();
+        assert_eq!(actual, back);
+    }
+    #[test]
+    fn should_serialize_keymap_sorted() {
+        let keymap: HashMap<String, KeyEvent> = ('a'..='z')
+            .map(|char| {
+                (
+                    char.to_string(),
+                    KeyEvent::new(KeyCode::Char(char), KeyModifiers::NONE),
+                )
+            })
+            .collect();
+        let input = Config { keymap };
+        let expected = serde_json::to_string(&input).unwrap();
+        for _ in 0..10 {
+            let copy = Config {
+                keymap: input.keymap.clone().into_iter().collect(),
+            };
+            assert_eq!(expected, serde_json::to_string(&copy).unwrap());
+        }
+        assert!(expected.starts_with(r#"{"keymap":{"a":"#));
+    }
+    #[test]
+    fn should_name_action_and_field_of_invalid_binding() {
+        #[derive(Debug, Deserialize)]
+        struct Config {
+            #[serde(deserialize_with = "super::deserialize_bindings_with_path")]
+            _keyboard: HashMap<String, KeyEvent>,
+        }
+        assert_case(
+            r#"{"move_up":{"code":"Up","modifiers":"AL"}}"#,
+            "move_up.modifiers: AL is not a valid keyword",
+        );
+        assert_case(
+            r#"{"move_up":{"code":"Up"},"quit":{"code":"Quitt"}}"#,
+            "quit.code: 'Quitt' is not a recognized key name",
+        );
+        assert_case(
+            r#"{"save":"CTRL+", "open":{"code":"o","modifiers":["CTRL","ALTT"]}}"#,
+            "open.modifiers: ALTT is not a valid keyword",
+        );
+        assert_case(
+            r#"{"save":"CTRL+"}"#,
+            "save: One char or a certain keyword must be provided",
+        );
+        fn assert_case(keyboard: &str, message: &str) {
+            let input = format!(r#"{{"_keyboard":{}}}"#, keyboard);
+            let actual = serde_json::from_str::<Config>(&input).unwrap_err();
+            let actual = actual.to_string();
+            assert!(
+                actual.starts_with(message),
+                "Message {} does not start with {}",
+                actual,
+                message
+            );
+        }
+    }
+    #[test]
+    fn should_read_bindings_with_path_like_default() {
+        let input = r#"{"a":"CTRL+a","b":{"code":{"f":5},"modifiers":["alt"]},"c":{"code":"Enter","keypad":true}}"#;
+        let expected: HashMap<String, KeyEvent> =
+            serde_json::from_str::<HashMap<String, KeyEventDef>>(input)
+                .unwrap()
+                .into_iter()
+                .map(|(action, KeyEventDef(event))| (action, event))
+                .collect();
+        let actual =
+            deserialize_bindings_with_path(&mut serde_json::Deserializer::from_str(input)).unwrap();
+        assert_eq!(expected, actual);
+        assert_eq!(crossterm::event::KeyEventState::NONE, actual["c"].state);
+    }
+    #[test]
+    fn should_round_trip_punctuation_key_codes_in_yaml() {
+        let input = Config {
+            keymap: HashMap::from([
+                (
+                    "command".to_string(),
+                    KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE),
+                ),
+                (
+                    "comment".to_string(),
+                    KeyEvent::new(KeyCode::Char('#'), KeyModifiers::CONTROL),
+                ),
+                (
+                    "zoom_out".to_string(),
+                    KeyEvent::new(KeyCode::Char('-'), KeyModifiers::NONE),
+                ),
+                (
+                    "search".to_string(),
+                    KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT),
+                ),
+                (
+                    "mark".to_string(),
+                    KeyEvent::new(KeyCode::Char('\''), KeyModifiers::ALT),
+                ),
+            ]),
+        };
+        let yaml = serde_yaml::to_string(&input).unwrap();
+        assert_eq!(input, serde_yaml::from_str(&yaml).unwrap(), "{}", yaml);
+    }
+}
@@ -0,0 +1,192 @@
+use crossterm::event::{KeyCode, KeyEvent, ModifierKeyCode};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+use crate::key_text::key_code;
+use crate::key_text::key_modifier::SEPERATOR;
+use crate::{compact, key_event_matches, KeyParseError};
+
+/// Key event which is pressed while a modifier key of one side is held like "LeftAlt+x".
+///
+/// A key event has one key code and its modifiers have no side, so ALT of "ALT+x" is the same
+/// for the left and the right ALT key. Terminals with the kitty keyboard protocol report pressing
+/// a modifier key on its own as key code like LeftAlt. An app which remembers this key can tell
+/// "LeftAlt+x" and "RightAlt+x" apart via [`SidedKeyEvent::matches`].
+///
+/// The text is a modifier key of one side followed by the compact form of the key event like
+/// "LeftAlt+x" or "RightShift+CONTROL+a". The modifier of the held key like ALT for LeftAlt is
+/// part of the key event, since the terminal reports it for the key code, but it is not written.
+/// Without a leading modifier key like "CONTROL+a" the text is only the compact form.
+/// A modifier key as the last part like "SHIFT+LeftAlt" stays the key code of the key event.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, ModifierKeyCode};
+/// use crossterm_serde::SidedKeyEvent;
+///
+/// let sided: SidedKeyEvent = serde_json::from_str(r#""LeftAlt+x""#).unwrap();
+/// assert_eq!(
+///     SidedKeyEvent {
+///         modifier_key: Some(ModifierKeyCode::LeftAlt),
+///         event: KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT)
+///     },
+///     sided
+/// );
+/// assert_eq!(r#""LeftAlt+x""#, serde_json::to_string(&sided).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SidedKeyEvent {
+    pub modifier_key: Option<ModifierKeyCode>,
+    pub event: KeyEvent,
+}
+
+impl SidedKeyEvent {
+    /// Returns true if the incoming key event matches while the given modifier key is held.
+    ///
+    /// Held is the modifier key of the last key event with a key code like LeftAlt, which is
+    /// still pressed. Without a modifier key of its own any held key matches.
+    pub fn matches(&self, held: Option<ModifierKeyCode>, incoming: &KeyEvent) -> bool {
+        let held_matches = self.modifier_key.is_none_or(|key| held == Some(key));
+        held_matches && key_event_matches(&self.event, incoming)
+    }
+
+    fn to_text(self) -> Result<String, KeyParseError> {
+        let Some(key) = self.modifier_key else {
+            return compact::key_event_to_string(&self.event);
+        };
+        let keyword = key_code::key_code_to_text(&KeyCode::Modifier(key))?;
+        let (_, modifier) = key_code::sided_modifier_key(&keyword)
+            .ok_or_else(|| KeyParseError::ModifierKeyAsModifier(keyword.to_string()))?;
+        let mut event = self.event;
+        event.modifiers.remove(modifier);
+        let event = compact::key_event_to_string(&event)?;
+        Ok(format!("{}{}{}", keyword, SEPERATOR, event))
+    }
+}
+
+impl FromStr for SidedKeyEvent {
+    type Err = KeyParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let held = text
+            .split_once(SEPERATOR)
+            .filter(|(_, event)| !event.trim().is_empty())
+            .and_then(|(key, event)| Some((key_code::sided_modifier_key(key.trim())?, event)));
+        match held {
+            Some(((key, modifier), event)) => {
+                let mut event = compact::key_event_from_str(event)?;
+                event.modifiers.insert(modifier);
+                Ok(Self {
+                    modifier_key: Some(key),
+                    event,
+                })
+            }
+            None => Ok(Self {
+                modifier_key: None,
+                event: compact::key_event_from_str(text)?,
+            }),
+        }
+    }
+}
+
+impl Serialize for SidedKeyEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let text = self.to_text().map_err(ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for SidedKeyEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn should_parse_left_and_right_modifier_key_to_distinct_events() {
+        let left: SidedKeyEvent = "LeftAlt+x".parse().unwrap();
+        let right: SidedKeyEvent = "RightAlt+x".parse().unwrap();
+        let event = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT);
+        assert_eq!(Some(ModifierKeyCode::LeftAlt), left.modifier_key);
+        assert_eq!(Some(ModifierKeyCode::RightAlt), right.modifier_key);
+        assert_eq!(event, left.event);
+        assert_eq!(event, right.event);
+        assert_ne!(left, right);
+    }
+    #[test]
+    fn should_match_only_with_held_modifier_key() {
+        let sided: SidedKeyEvent = "LeftAlt+x".parse().unwrap();
+        let incoming = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT);
+        assert!(sided.matches(Some(ModifierKeyCode::LeftAlt), &incoming));
+        assert!(!sided.matches(Some(ModifierKeyCode::RightAlt), &incoming));
+        assert!(!sided.matches(None, &incoming));
+        let unsided: SidedKeyEvent = "ALT+x".parse().unwrap();
+        assert!(unsided.matches(Some(ModifierKeyCode::RightAlt), &incoming));
+    }
+    #[test]
+    fn should_keep_modifier_key_as_last_part_as_key_code() {
+        assert_case(
+            "SHIFT+LeftAlt",
+            KeyEvent::new(
+                KeyCode::Modifier(ModifierKeyCode::LeftAlt),
+                KeyModifiers::SHIFT,
+            ),
+        );
+        assert_case(
+            "LeftAlt",
+            KeyEvent::new(
+                KeyCode::Modifier(ModifierKeyCode::LeftAlt),
+                KeyModifiers::NONE,
+            ),
+        );
+        fn assert_case(input: &str, event: KeyEvent) {
+            let actual: SidedKeyEvent = input.parse().unwrap();
+            assert_eq!(
+                SidedKeyEvent {
+                    modifier_key: None,
+                    event
+                },
+                actual
+            );
+        }
+    }
+    #[test]
+    fn should_deny_several_or_unsided_leading_modifier_keys() {
+        assert_eq!(
+            Err(KeyParseError::ModifierKeyAsModifier("RightAlt".to_string())),
+            "LeftAlt+RightAlt+x".parse::<SidedKeyEvent>()
+        );
+        assert_eq!(
+            Err(KeyParseError::ModifierKeyAsModifier(
+                "IsoLevel3Shift".to_string()
+            )),
+            "IsoLevel3Shift+x".parse::<SidedKeyEvent>()
+        );
+    }
+    #[test]
+    fn should_round_trip_via_serde() {
+        assert_case(r#""LeftAlt+x""#);
+        assert_case(r#""RightShift+CONTROL+a""#);
+        assert_case(r#""CONTROL+a""#);
+        assert_case(r#""SHIFT+LeftAlt""#);
+        fn assert_case(input: &str) {
+            let sided: SidedKeyEvent = serde_json::from_str(input).unwrap();
+            let actual = serde_json::to_string(&sided).unwrap();
+            assert_eq!(input, actual);
+        }
+    }
+}